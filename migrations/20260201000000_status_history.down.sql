@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_status_history_application;
+DROP TABLE status_history;
@@ -0,0 +1,13 @@
+CREATE TABLE status_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    application_id TEXT NOT NULL,
+    status TEXT NOT NULL,
+    changed_at TEXT NOT NULL,
+    FOREIGN KEY (application_id) REFERENCES applications(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_status_history_application ON status_history(application_id);
+
+-- Seed the history with the status each existing application currently has
+INSERT INTO status_history (application_id, status, changed_at)
+SELECT id, status, date FROM applications;
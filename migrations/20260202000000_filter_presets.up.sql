@@ -0,0 +1,9 @@
+CREATE TABLE filter_presets (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL UNIQUE,
+    status TEXT,
+    search TEXT,
+    sort TEXT NOT NULL,
+    date_from TEXT,
+    date_to TEXT
+);
@@ -0,0 +1,3 @@
+-- RFC3339 values are still readable as text; there is no need to restore
+-- the old Display format.
+SELECT 1;
@@ -0,0 +1,24 @@
+-- Dates used to be written with OffsetDateTime's Display impl, e.g.
+-- "2026-01-27 9:05:03.123456 +00:00:00". Rewrite them as RFC3339, e.g.
+-- "2026-01-27T09:05:03.123456Z". Rows already in RFC3339 have a 'T' at
+-- position 11 and are left untouched, so this is safe to re-run.
+
+-- Zero-pad single digit hours
+UPDATE applications
+SET date = substr(date, 1, 11) || '0' || substr(date, 12)
+WHERE substr(date, 11, 1) = ' ' AND substr(date, 13, 1) = ':';
+
+UPDATE status_history
+SET changed_at = substr(changed_at, 1, 11) || '0' || substr(changed_at, 12)
+WHERE substr(changed_at, 11, 1) = ' ' AND substr(changed_at, 13, 1) = ':';
+
+-- Join date and time with 'T' and shorten the "+HH:MM:SS" offset to "+HH:MM" (or "Z")
+UPDATE applications
+SET date = substr(date, 1, 10) || 'T' || substr(date, 12, length(date) - 21) ||
+    CASE substr(date, -9) WHEN '+00:00:00' THEN 'Z' ELSE substr(date, -9, 6) END
+WHERE substr(date, 11, 1) = ' ';
+
+UPDATE status_history
+SET changed_at = substr(changed_at, 1, 10) || 'T' || substr(changed_at, 12, length(changed_at) - 21) ||
+    CASE substr(changed_at, -9) WHEN '+00:00:00' THEN 'Z' ELSE substr(changed_at, -9, 6) END
+WHERE substr(changed_at, 11, 1) = ' ';
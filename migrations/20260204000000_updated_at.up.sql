@@ -0,0 +1,3 @@
+ALTER TABLE applications ADD COLUMN updated_at TEXT NOT NULL DEFAULT '';
+
+UPDATE applications SET updated_at = date WHERE updated_at = '';
@@ -0,0 +1 @@
+ALTER TABLE applications ADD COLUMN follow_up TEXT;
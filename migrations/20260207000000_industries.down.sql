@@ -0,0 +1,3 @@
+ALTER TABLE companies DROP COLUMN industry_id;
+DROP TABLE industry_aliases;
+DROP TABLE industries;
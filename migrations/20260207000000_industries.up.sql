@@ -0,0 +1,62 @@
+CREATE TABLE industries (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL UNIQUE
+);
+
+-- Alternative spellings that map onto a canonical industry, stored in the
+-- lowercase, single-spaced form the server normalizes input to
+CREATE TABLE industry_aliases (
+    alias TEXT PRIMARY KEY NOT NULL,
+    industry_id TEXT NOT NULL,
+    FOREIGN KEY (industry_id) REFERENCES industries(id) ON DELETE CASCADE
+);
+
+INSERT INTO industries (id, name) VALUES
+    ('software', 'Software'),
+    ('finance', 'Finance'),
+    ('healthcare', 'Healthcare'),
+    ('education', 'Education'),
+    ('retail', 'Retail'),
+    ('manufacturing', 'Manufacturing'),
+    ('consulting', 'Consulting'),
+    ('media', 'Media'),
+    ('gaming', 'Gaming'),
+    ('telecom', 'Telecommunications'),
+    ('energy', 'Energy'),
+    ('logistics', 'Logistics'),
+    ('government', 'Government'),
+    ('nonprofit', 'Non-profit');
+
+INSERT INTO industry_aliases (alias, industry_id) VALUES
+    ('tech', 'software'),
+    ('technology', 'software'),
+    ('it', 'software'),
+    ('saas', 'software'),
+    ('software development', 'software'),
+    ('fintech', 'finance'),
+    ('banking', 'finance'),
+    ('insurance', 'finance'),
+    ('health', 'healthcare'),
+    ('medical', 'healthcare'),
+    ('edtech', 'education'),
+    ('e-commerce', 'retail'),
+    ('ecommerce', 'retail'),
+    ('entertainment', 'media'),
+    ('games', 'gaming'),
+    ('video games', 'gaming'),
+    ('telecommunication', 'telecom'),
+    ('transport', 'logistics'),
+    ('transportation', 'logistics'),
+    ('public sector', 'government'),
+    ('non profit', 'nonprofit'),
+    ('ngo', 'nonprofit');
+
+-- NULL means the free-text industry matched nothing and counts as "Other"
+ALTER TABLE companies ADD COLUMN industry_id TEXT REFERENCES industries(id);
+
+UPDATE companies SET industry_id = (
+    SELECT id FROM industries WHERE lower(name) = lower(trim(companies.industry))
+    UNION ALL
+    SELECT industry_id FROM industry_aliases WHERE alias = lower(trim(companies.industry))
+    LIMIT 1
+);
@@ -0,0 +1,6 @@
+-- A single row holding the user's preferences as JSON, so new settings
+-- don't need a migration each
+CREATE TABLE preferences (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    data TEXT NOT NULL
+);
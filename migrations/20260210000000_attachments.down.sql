@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_attachments_application;
+DROP TABLE attachments;
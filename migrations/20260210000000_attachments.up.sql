@@ -0,0 +1,14 @@
+-- Files uploaded to an application, kept in the database so a backup of
+-- data.db stays a complete backup
+CREATE TABLE attachments (
+    id TEXT PRIMARY KEY NOT NULL,
+    application_id TEXT NOT NULL,
+    file_name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size INTEGER NOT NULL,
+    data BLOB NOT NULL,
+    uploaded_at TEXT NOT NULL,
+    FOREIGN KEY (application_id) REFERENCES applications(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_attachments_application ON attachments(application_id);
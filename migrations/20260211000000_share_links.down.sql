@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_share_links_application;
+DROP TABLE share_links;
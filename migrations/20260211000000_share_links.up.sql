@@ -0,0 +1,11 @@
+-- Public, read-only links to a single application. Revoking keeps the row
+-- so an old link can be told apart from one that never existed
+CREATE TABLE share_links (
+    token TEXT PRIMARY KEY NOT NULL,
+    application_id TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    revoked_at TEXT,
+    FOREIGN KEY (application_id) REFERENCES applications(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_share_links_application ON share_links(application_id);
@@ -0,0 +1,2 @@
+-- Why a status was set, for changes the app made on the user's behalf
+ALTER TABLE status_history ADD COLUMN reason TEXT;
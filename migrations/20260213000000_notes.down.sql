@@ -0,0 +1,2 @@
+ALTER TABLE filter_presets DROP COLUMN search_notes;
+ALTER TABLE applications DROP COLUMN notes;
@@ -0,0 +1,3 @@
+-- Free-form notes per application, and whether a saved filter searches them
+ALTER TABLE applications ADD COLUMN notes TEXT NOT NULL DEFAULT '';
+ALTER TABLE filter_presets ADD COLUMN search_notes INTEGER NOT NULL DEFAULT 0;
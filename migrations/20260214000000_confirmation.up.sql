@@ -0,0 +1,3 @@
+-- Whether the company confirmed receiving a sent application: NULL until it
+-- is sent, then 0 until the confirmation is marked as received
+ALTER TABLE applications ADD COLUMN confirmation_received INTEGER;
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_reminders_due;
+DROP INDEX IF EXISTS idx_reminders_application;
+DROP TABLE reminders;
@@ -0,0 +1,13 @@
+-- Free-form notes to self that fall due on a date, apart from the single
+-- follow-up date of an application
+CREATE TABLE reminders (
+    id TEXT PRIMARY KEY NOT NULL,
+    application_id TEXT NOT NULL,
+    remind_at TEXT NOT NULL,
+    text TEXT NOT NULL,
+    done INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY (application_id) REFERENCES applications(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_reminders_application ON reminders(application_id);
+CREATE INDEX idx_reminders_due ON reminders(done, remind_at);
@@ -0,0 +1 @@
+DROP TABLE anonymization_salt;
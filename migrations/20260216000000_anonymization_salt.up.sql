@@ -0,0 +1,6 @@
+-- The secret anonymized exports hash real values with, created on the first
+-- export so every later one picks the same placeholders
+CREATE TABLE anonymization_salt (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    salt TEXT NOT NULL
+);
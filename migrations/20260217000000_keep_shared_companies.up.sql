@@ -0,0 +1,9 @@
+-- Companies can have several applications since they can be reassigned, so
+-- only drop a company once its last application is gone
+DROP TRIGGER IF EXISTS delete_company_after_application;
+CREATE TRIGGER delete_company_after_application
+AFTER DELETE ON applications
+BEGIN
+    DELETE FROM companies WHERE id = OLD.company_id
+        AND NOT EXISTS (SELECT 1 FROM applications WHERE company_id = OLD.company_id);
+END;
@@ -0,0 +1,6 @@
+-- When the weekly digest last went out, so a restart does not push the next
+-- one back by a whole week
+CREATE TABLE digest_runs (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    sent_at TEXT NOT NULL
+);
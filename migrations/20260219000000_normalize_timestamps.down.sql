@@ -0,0 +1,3 @@
+-- The normalized values are still valid RFC3339 and dates; there is no need
+-- to restore the old forms.
+SELECT 1;
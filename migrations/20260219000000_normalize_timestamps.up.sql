@@ -0,0 +1,43 @@
+-- Timestamps are now written in UTC with nine subsecond digits, e.g.
+-- "2026-02-19T08:05:03.120000000Z", so they compare correctly as text. Rewrite
+-- older RFC3339 values to match: other offsets are moved to UTC, keeping the
+-- subsecond digits they had, and fewer than nine are padded with zeroes.
+-- Follow-ups and deadlines are calendar days, so any time written after the
+-- day is dropped rather than moved to UTC. Values already in shape, or that
+-- SQLite cannot read, are left untouched, so this is safe to re-run.
+
+UPDATE applications
+SET date = strftime('%Y-%m-%dT%H:%M:%S', date) || '.' || substr(
+    CASE WHEN substr(date, 20, 1) = '.'
+        THEN substr(date, 21, length(date) - 20 - CASE WHEN date LIKE '%Z' THEN 1 ELSE 6 END)
+        ELSE '' END || '000000000', 1, 9) || 'Z'
+WHERE date NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%s', date) IS NOT NULL;
+
+UPDATE applications
+SET updated_at = strftime('%Y-%m-%dT%H:%M:%S', updated_at) || '.' || substr(
+    CASE WHEN substr(updated_at, 20, 1) = '.'
+        THEN substr(updated_at, 21, length(updated_at) - 20 - CASE WHEN updated_at LIKE '%Z' THEN 1 ELSE 6 END)
+        ELSE '' END || '000000000', 1, 9) || 'Z'
+WHERE updated_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%s', updated_at) IS NOT NULL;
+
+UPDATE applications
+SET archived_at = strftime('%Y-%m-%dT%H:%M:%S', archived_at) || '.' || substr(
+    CASE WHEN substr(archived_at, 20, 1) = '.'
+        THEN substr(archived_at, 21, length(archived_at) - 20 - CASE WHEN archived_at LIKE '%Z' THEN 1 ELSE 6 END)
+        ELSE '' END || '000000000', 1, 9) || 'Z'
+WHERE archived_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%s', archived_at) IS NOT NULL;
+
+UPDATE status_history
+SET changed_at = strftime('%Y-%m-%dT%H:%M:%S', changed_at) || '.' || substr(
+    CASE WHEN substr(changed_at, 20, 1) = '.'
+        THEN substr(changed_at, 21, length(changed_at) - 20 - CASE WHEN changed_at LIKE '%Z' THEN 1 ELSE 6 END)
+        ELSE '' END || '000000000', 1, 9) || 'Z'
+WHERE changed_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%s', changed_at) IS NOT NULL;
+
+UPDATE applications
+SET follow_up = substr(follow_up, 1, 10)
+WHERE length(follow_up) > 10 AND date(substr(follow_up, 1, 10)) = substr(follow_up, 1, 10);
+
+UPDATE applications
+SET deadline = substr(deadline, 1, 10)
+WHERE length(deadline) > 10 AND date(substr(deadline, 1, 10)) = substr(deadline, 1, 10);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_note_edits_application;
+DROP TABLE note_edits;
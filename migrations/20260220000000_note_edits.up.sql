@@ -0,0 +1,9 @@
+-- When the notes of an application were edited, for the activity timeline
+CREATE TABLE note_edits (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    application_id TEXT NOT NULL,
+    edited_at TEXT NOT NULL,
+    FOREIGN KEY (application_id) REFERENCES applications(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_note_edits_application ON note_edits(application_id);
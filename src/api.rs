@@ -0,0 +1,840 @@
+//! Plain JSON endpoints for clients other than the Leptos frontend.
+
+use crate::app::{
+    date_to_db, fetch_applications, share_link_is_live, AllApplicationsResponse, ApplicationFilter,
+    AttachmentPreview, ImportApplications, ATTACHMENT_QUOTA_BYTES,
+};
+use crate::rate_limit::{self, RateLimiter};
+use axum::{
+    body::{Body, Bytes},
+    extract::{
+        multipart::{Multipart, MultipartError},
+        DefaultBodyLimit, Path, Request, State,
+    },
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Redirect, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use http_body_util::Limited;
+use leptos::server_fn::ServerFn;
+use qrcode::{render::svg, QrCode};
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::{self, Seek, Write},
+};
+use time::OffsetDateTime;
+use tokio::{runtime::Handle, sync::mpsc};
+use tokio_stream::wrappers::ReceiverStream;
+use tower_http::limit::RequestBodyLimitLayer;
+use utoipa::{OpenApi, ToSchema};
+use uuid::Uuid;
+use zip::{write::SimpleFileOptions, ZipWriter};
+
+/// Largest request body the server accepts, which comfortably fits any form
+/// post. Attachment uploads get [`MAX_UPLOAD_BYTES`] instead.
+pub const MAX_REQUEST_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// Largest attachment upload, which leaves room for a scanned PDF.
+const MAX_UPLOAD_BYTES: usize = 25 * 1024 * 1024;
+
+/// Largest archive import. A URL-encoded export takes about 500 bytes per
+/// application, so this fits tens of thousands.
+const MAX_IMPORT_BYTES: usize = 32 * 1024 * 1024;
+
+/// Middleware capping request bodies to the Leptos routes at
+/// [`MAX_REQUEST_BODY_BYTES`], or [`MAX_IMPORT_BYTES`] for the server
+/// function archives are imported through. A body declared too large is
+/// rejected with 413 before it is read; one that turns out too large fails
+/// while being read.
+pub async fn limit_page_bodies(request: Request, next: Next) -> Response {
+    let limit = if request.uri().path() == ImportApplications::PATH {
+        MAX_IMPORT_BYTES
+    } else {
+        MAX_REQUEST_BODY_BYTES
+    };
+    let declared = request
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|length| length.to_str().ok()?.parse::<usize>().ok());
+    if declared.is_some_and(|length| length > limit) {
+        return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+    }
+
+    next.run(request.map(|body| Body::new(Limited::new(body, limit))))
+        .await
+}
+
+/// The REST API. Only the endpoints meant for other clients go through
+/// `limiter`; the rest serve the app's own pages, which may call them many
+/// times over, e.g. for every preview in the gallery.
+pub fn router<S>(pool: SqlitePool, limiter: RateLimiter) -> Router<S> {
+    // Both limits, as axum's own default would still cut the multipart
+    // body off at 2 MiB
+    let uploads = Router::new()
+        .route(
+            "/api/applications/{id}/attachments",
+            post(upload_attachment),
+        )
+        .layer(DefaultBodyLimit::max(MAX_UPLOAD_BYTES))
+        .layer(RequestBodyLimitLayer::new(MAX_UPLOAD_BYTES));
+
+    let public = Router::new()
+        .route("/api/applications", get(list_applications))
+        .route("/api/openapi.json", get(openapi))
+        .route_layer(middleware::from_fn_with_state(limiter, rate_limit::limit));
+
+    Router::new()
+        .merge(public)
+        .route("/api/attachments/{id}", get(download_attachment))
+        .route("/api/attachments.zip", get(export_attachments))
+        .route("/api/share/{token}/qr.svg", get(share_qr_code))
+        .route("/api/companies/{id}/contacts.vcf", get(company_vcard))
+        // Reject oversized bodies with 413 before they are buffered
+        .layer(RequestBodyLimitLayer::new(MAX_REQUEST_BODY_BYTES))
+        .merge(uploads)
+        .with_state(pool)
+}
+
+/// The contract of the endpoints below, built from their `utoipa::path`
+/// attributes and the schemas of the types they return.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Soulcrush API",
+        description = "Plain JSON endpoints for job applications."
+    ),
+    paths(
+        list_applications,
+        upload_attachment,
+        download_attachment,
+        export_attachments,
+        share_qr_code,
+        company_vcard
+    )
+)]
+struct ApiDoc;
+
+/// Serves the OpenAPI document describing this API.
+async fn openapi() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+/// Lists all live (not archived) applications, newest first.
+///
+/// Responses carry a weak ETag derived from every listed application's id and
+/// `updated_at` and its company's details, so polling clients sending
+/// `If-None-Match` get a bodyless 304 while nothing has changed.
+#[utoipa::path(
+    get,
+    path = "/api/applications",
+    responses(
+        (status = 200, description = "Live applications, newest first", body = Vec<AllApplicationsResponse>),
+        (status = 304, description = "Nothing changed since the ETag in `If-None-Match`"),
+    )
+)]
+async fn list_applications(State(pool): State<SqlitePool>, headers: HeaderMap) -> Response {
+    let etag = match list_etag(&pool).await {
+        Ok(etag) => etag,
+        Err(e) => return internal_error(e),
+    };
+
+    if if_none_match(&headers, &etag) {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response();
+    }
+
+    match fetch_applications(&pool, &ApplicationFilter::default()).await {
+        Ok(applications) => ([(header::ETAG, etag)], Json(applications)).into_response(),
+        Err(e) => internal_error(e),
+    }
+}
+
+/// The ETag of the list. Editing a company or normalizing its website leaves
+/// its applications' `updated_at` alone, so company details go into the hash
+/// as well. SHA-256 keeps the tag the same across restarts and Rust versions,
+/// unlike the standard library's hasher.
+async fn list_etag(pool: &SqlitePool) -> Result<HeaderValue, sqlx::Error> {
+    let rows: Vec<(String, String, String, String, String, String)> = sqlx::query_as(
+        r#"
+        SELECT a.id, a.updated_at, c.name, c.website, c.ceo, c.industry
+        FROM applications a JOIN companies c ON a.company_id = c.id
+        WHERE a.archived_at IS NULL
+        ORDER BY a.id
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut hasher = Sha256::new();
+    for (id, updated_at, name, website, ceo, industry) in &rows {
+        for field in [id, updated_at, name, website, ceo, industry] {
+            hasher.update(field);
+            hasher.update([0u8]);
+        }
+    }
+    let digest: String = hasher.finalize()[..8]
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+
+    Ok(
+        HeaderValue::from_str(&format!("W/\"{}-{digest}\"", rows.len()))
+            .expect("ETag is always a valid header value"),
+    )
+}
+
+/// Whether any of the tags in `If-None-Match` matches `etag`, using the weak
+/// comparison RFC 9110 prescribes for this header.
+fn if_none_match(headers: &HeaderMap, etag: &HeaderValue) -> bool {
+    let Some(Ok(candidates)) = headers.get(header::IF_NONE_MATCH).map(|v| v.to_str()) else {
+        return false;
+    };
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_owned();
+    let etag = opaque(etag.to_str().unwrap_or_default());
+
+    candidates
+        .split(',')
+        .any(|tag| tag.trim() == "*" || opaque(tag) == etag)
+}
+
+/// Stores the file posted in the `file` field of a multipart form as an
+/// attachment of application `id`, then sends the browser back to the
+/// application.
+///
+/// Uploads that would take the attachments past [`ATTACHMENT_QUOTA_BYTES`]
+/// are refused with 413, and so are files over [`MAX_UPLOAD_BYTES`].
+#[utoipa::path(
+    post,
+    path = "/api/applications/{id}/attachments",
+    params(("id" = Uuid, Path, description = "The application to attach the file to")),
+    request_body(content = UploadForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 303, description = "Stored; redirects to the application"),
+        (status = 400, description = "No file was uploaded, or the form is malformed"),
+        (status = 404, description = "No such application"),
+        (status = 413, description = "The file is too large, or does not fit in the attachment quota"),
+    )
+)]
+async fn upload_attachment(
+    State(pool): State<SqlitePool>,
+    Path(id): Path<Uuid>,
+    mut multipart: Multipart,
+) -> Result<Response, Response> {
+    let UploadForm { file: upload } = read_upload(&mut multipart)
+        .await
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()).into_response())?
+        .ok_or_else(|| (StatusCode::BAD_REQUEST, "No file was uploaded").into_response())?;
+
+    let mut tx = pool.begin().await.map_err(internal_error)?;
+
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM applications WHERE id = ?)")
+        .bind(id.to_string())
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(internal_error)?;
+    if !exists {
+        return Err(StatusCode::NOT_FOUND.into_response());
+    }
+
+    let used: i64 = sqlx::query_scalar("SELECT COALESCE(SUM(size), 0) FROM attachments")
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(internal_error)?;
+    let size = upload.data.len() as i64;
+    if used + size > ATTACHMENT_QUOTA_BYTES {
+        return Err((
+            StatusCode::PAYLOAD_TOO_LARGE,
+            "This file does not fit in the attachment quota",
+        )
+            .into_response());
+    }
+
+    sqlx::query(
+        "INSERT INTO attachments (id, application_id, file_name, content_type, size, data, uploaded_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(id.to_string())
+    .bind(&upload.file_name)
+    .bind(&upload.content_type)
+    .bind(size)
+    .bind(upload.data.as_ref())
+    .bind(date_to_db(OffsetDateTime::now_utc()))
+    .execute(&mut *tx)
+    .await
+    .map_err(internal_error)?;
+
+    tx.commit().await.map_err(internal_error)?;
+    Ok(Redirect::to(&format!("/applications/{id}")).into_response())
+}
+
+/// The form [`upload_attachment`] reads.
+#[derive(ToSchema)]
+struct UploadForm {
+    #[schema(value_type = String, format = Binary)]
+    file: Upload,
+}
+
+/// A file sent in the `file` field of an [`UploadForm`].
+struct Upload {
+    file_name: String,
+    content_type: String,
+    data: Bytes,
+}
+
+/// Reads the `file` field of a multipart form. A file input left empty
+/// still submits the field, without a name or content, which counts as no
+/// upload.
+async fn read_upload(multipart: &mut Multipart) -> Result<Option<UploadForm>, MultipartError> {
+    while let Some(field) = multipart.next_field().await? {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let file_name = field.file_name().unwrap_or_default().trim().to_string();
+        let content_type = field
+            .content_type()
+            .unwrap_or("application/octet-stream")
+            .to_string();
+        let data = field.bytes().await?;
+        if file_name.is_empty() || data.is_empty() {
+            return Ok(None);
+        }
+        return Ok(Some(UploadForm {
+            file: Upload {
+                file_name,
+                content_type,
+                data,
+            },
+        }));
+    }
+
+    Ok(None)
+}
+
+/// Serves the content of attachment `id`. Only types the gallery can
+/// preview are shown inline; anything else is sent as a download, so an
+/// uploaded page can never run in the app's origin.
+#[utoipa::path(
+    get,
+    path = "/api/attachments/{id}",
+    params(("id" = Uuid, Path, description = "The attachment to download")),
+    responses(
+        (status = 200, description = "The file content", content_type = "application/octet-stream", body = Vec<u8>),
+        (status = 404, description = "No such attachment"),
+    )
+)]
+async fn download_attachment(State(pool): State<SqlitePool>, Path(id): Path<Uuid>) -> Response {
+    let row: Option<(String, String, Vec<u8>)> =
+        match sqlx::query_as("SELECT file_name, content_type, data FROM attachments WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&pool)
+            .await
+        {
+            Ok(row) => row,
+            Err(e) => return internal_error(e),
+        };
+    let Some((file_name, content_type, data)) = row else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let file_name = header_file_name(&file_name);
+    let (content_type, disposition) = match AttachmentPreview::of(&content_type) {
+        AttachmentPreview::Download => ("application/octet-stream".to_string(), "attachment"),
+        _ => (content_type, "inline"),
+    };
+
+    (
+        [
+            (header::CONTENT_TYPE, content_type),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("{disposition}; filename=\"{file_name}\""),
+            ),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+            // Attachments never change once uploaded
+            (
+                header::CACHE_CONTROL,
+                "private, max-age=31536000, immutable".to_string(),
+            ),
+        ],
+        data,
+    )
+        .into_response()
+}
+
+/// Downloads every attachment as one zip, with a folder per company and one
+/// per application within it.
+///
+/// The archive is built in a temporary file, reading one attachment at a
+/// time, and then streamed from it, so only about one file is held in memory
+/// however many there are.
+#[utoipa::path(
+    get,
+    path = "/api/attachments.zip",
+    responses(
+        (status = 200, description = "A zip of all attachments", content_type = "application/zip", body = Vec<u8>),
+        (status = 404, description = "There are no attachments to export"),
+    )
+)]
+async fn export_attachments(State(pool): State<SqlitePool>) -> Response {
+    let entries: Vec<ArchiveEntry> = match sqlx::query_as(
+        r#"
+        SELECT t.id, t.file_name, c.name, a.id, a.date
+        FROM attachments t
+        JOIN applications a ON t.application_id = a.id
+        JOIN companies c ON a.company_id = c.id
+        ORDER BY c.name, a.date, t.uploaded_at
+        "#,
+    )
+    .fetch_all(&pool)
+    .await
+    {
+        Ok(entries) => entries,
+        Err(e) => return internal_error(e),
+    };
+    if entries.is_empty() {
+        return (StatusCode::NOT_FOUND, "There are no attachments to export.").into_response();
+    }
+
+    // The zip writer is blocking, so it runs on its own thread and hands
+    // the archive over in chunks as the body is read. It seeks back to fill
+    // in each entry's header, so it writes to a file rather than the body.
+    let (sender, receiver) = mpsc::channel(4);
+    let runtime = Handle::current();
+    tokio::task::spawn_blocking(move || {
+        let mut writer = ChannelWriter {
+            sender: sender.clone(),
+            buffer: Vec::with_capacity(ChannelWriter::CHUNK_SIZE),
+        };
+        let path = std::env::temp_dir().join(format!("soulcrush-{}.zip", Uuid::new_v4()));
+        let result = File::options()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .and_then(|file| {
+                let mut file = write_attachments_zip(&runtime, &pool, entries, file)?;
+                file.rewind()?;
+                io::copy(&mut file, &mut writer)?;
+                writer.flush()
+            });
+        if let Err(e) = std::fs::remove_file(&path) {
+            tracing::warn!("Failed to remove {}: {e}", path.display());
+        }
+        if let Err(e) = result {
+            tracing::error!("Failed to export attachments: {e}");
+            // Ends the body with an error, so the download fails instead of
+            // leaving a truncated archive that looks complete
+            let _ = sender.blocking_send(Err(e));
+        }
+    });
+
+    (
+        [
+            (header::CONTENT_TYPE, "application/zip"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"attachments.zip\"",
+            ),
+        ],
+        Body::from_stream(ReceiverStream::new(receiver)),
+    )
+        .into_response()
+}
+
+/// An attachment to put in the export: its id and file name, and the company
+/// name, id and date of its application.
+type ArchiveEntry = (String, String, String, String, String);
+
+fn write_attachments_zip(
+    runtime: &Handle,
+    pool: &SqlitePool,
+    entries: Vec<ArchiveEntry>,
+    file: File,
+) -> io::Result<File> {
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default();
+    let mut paths = HashSet::new();
+
+    for (id, file_name, company, application_id, date) in entries {
+        let data: Vec<u8> = runtime
+            .block_on(
+                sqlx::query_scalar("SELECT data FROM attachments WHERE id = ?")
+                    .bind(&id)
+                    .fetch_one(pool),
+            )
+            .map_err(io::Error::other)?;
+
+        let folder = format!(
+            "{}/{} {}",
+            archive_name(&company),
+            date.get(..10).unwrap_or(&date),
+            application_id.get(..8).unwrap_or(&application_id),
+        );
+        let path = unique_path(&mut paths, &folder, &archive_name(&file_name));
+        zip.start_file(path, options).map_err(io::Error::other)?;
+        zip.write_all(&data)?;
+    }
+
+    zip.finish().map_err(io::Error::other)
+}
+
+/// Makes `name` usable as one segment of a path in the archive.
+fn archive_name(name: &str) -> String {
+    let name: String = name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_control() || c == '/' || c == '\\' {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    match name.as_str() {
+        "" | "." | ".." => "_".to_string(),
+        _ => name,
+    }
+}
+
+/// `folder/name`, numbered like `name (2).pdf` when an earlier attachment of
+/// the same application already took it.
+fn unique_path(paths: &mut HashSet<String>, folder: &str, name: &str) -> String {
+    let (stem, extension) = match name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, format!(".{extension}")),
+        _ => (name, String::new()),
+    };
+    let mut path = format!("{folder}/{name}");
+    let mut copy = 2;
+    while !paths.insert(path.clone()) {
+        path = format!("{folder}/{stem} ({copy}){extension}");
+        copy += 1;
+    }
+    path
+}
+
+/// Sends what is written to it down a channel in chunks, for the body of a
+/// streamed response. Writing fails once the client has gone away.
+struct ChannelWriter {
+    sender: mpsc::Sender<io::Result<Bytes>>,
+    buffer: Vec<u8>,
+}
+
+impl ChannelWriter {
+    const CHUNK_SIZE: usize = 64 * 1024;
+}
+
+impl Write for ChannelWriter {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(data);
+        if self.buffer.len() >= Self::CHUNK_SIZE {
+            self.flush()?;
+        }
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let chunk = Bytes::from(std::mem::replace(
+            &mut self.buffer,
+            Vec::with_capacity(Self::CHUNK_SIZE),
+        ));
+        self.sender
+            .blocking_send(Ok(chunk))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "download cancelled"))
+    }
+}
+
+/// Makes `name` safe to put in a `Content-Disposition` filename: header
+/// values must be visible ASCII, and the name sits inside quotes.
+fn header_file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c == ' ' || (c.is_ascii_graphic() && c != '"' && c != '\\') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Exports the contacts of company `id` as a vCard for an address book. The
+/// CEO is the only contact stored; a company without one still gets a card
+/// with just its name and website.
+#[utoipa::path(
+    get,
+    path = "/api/companies/{id}/contacts.vcf",
+    params(("id" = Uuid, Path, description = "The company to export")),
+    responses(
+        (status = 200, description = "A vCard 3.0 card", content_type = "text/vcard", body = String),
+        (status = 404, description = "No such company"),
+    )
+)]
+async fn company_vcard(State(pool): State<SqlitePool>, Path(id): Path<Uuid>) -> Response {
+    let row: Option<(String, String, String)> =
+        match sqlx::query_as("SELECT name, website, ceo FROM companies WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&pool)
+            .await
+        {
+            Ok(row) => row,
+            Err(e) => return internal_error(e),
+        };
+    let Some((name, website, ceo)) = row else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    // Websites are stored with their scheme, as normalize_website leaves them
+    let url = website.trim();
+    let url = (!url.is_empty()).then_some(url);
+    let ceo = ceo.trim();
+    let card = if ceo.is_empty() {
+        vcard(&name, None, &name, url)
+    } else {
+        vcard(ceo, Some("CEO"), &name, url)
+    };
+
+    (
+        [
+            (
+                header::CONTENT_TYPE,
+                "text/vcard; charset=utf-8".to_string(),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}.vcf\"", header_file_name(&name)),
+            ),
+        ],
+        card,
+    )
+        .into_response()
+}
+
+/// One vCard 3.0 card for `full_name` at `org`.
+fn vcard(full_name: &str, title: Option<&str>, org: &str, url: Option<&str>) -> String {
+    // The structured name wants family and given names apart; guess that
+    // the family name is the last word
+    let full_name = full_name.trim();
+    let (given, family) = full_name.rsplit_once(' ').unwrap_or(("", full_name));
+
+    let mut lines = vec![
+        "BEGIN:VCARD".to_string(),
+        "VERSION:3.0".to_string(),
+        format!("FN:{}", vcard_escape(full_name)),
+        format!("N:{};{};;;", vcard_escape(family), vcard_escape(given)),
+        format!("ORG:{}", vcard_escape(org)),
+    ];
+    if let Some(title) = title {
+        lines.push(format!("TITLE:{}", vcard_escape(title)));
+    }
+    // A URI rather than text, so it is not escaped; only control characters
+    // are dropped, as they could break the card apart
+    if let Some(url) = url {
+        lines.push(format!("URL:{}", url.replace(char::is_control, "")));
+    }
+    lines.push("END:VCARD".to_string());
+
+    lines.iter().map(|line| vcard_fold(line)).collect()
+}
+
+/// Escapes a text value as the vCard spec requires: backslashes, commas,
+/// semicolons and newlines.
+fn vcard_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ',' => escaped.push_str("\\,"),
+            ';' => escaped.push_str("\\;"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Ends `line` with CRLF, folding it so no line exceeds 75 octets, without
+/// splitting a character.
+fn vcard_fold(line: &str) -> String {
+    const MAX_OCTETS: usize = 75;
+
+    let mut folded = String::with_capacity(line.len() + 8);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_OCTETS {
+            // A continuation line starts with a space, which counts too
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+/// Renders the public URL of share link `token` as an SVG QR code. A revoked
+/// or unknown link gets 404 rather than a code that leads to a dead page.
+#[utoipa::path(
+    get,
+    path = "/api/share/{token}/qr.svg",
+    params(("token" = String, Path, description = "The share link token")),
+    responses(
+        (status = 200, description = "The QR code", content_type = "image/svg+xml", body = String),
+        (status = 404, description = "The share link was revoked or never existed"),
+    )
+)]
+async fn share_qr_code(
+    State(pool): State<SqlitePool>,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+) -> Response {
+    match share_link_is_live(&pool, &token).await {
+        Ok(true) => {}
+        Ok(false) => {
+            return (StatusCode::NOT_FOUND, "This share link was revoked").into_response();
+        }
+        Err(e) => return internal_error(e),
+    }
+
+    let url = format!("{}/share/{token}", public_origin(&headers));
+    let code = match QrCode::new(url.as_bytes()) {
+        Ok(code) => code,
+        Err(e) => return internal_error(e),
+    };
+    let image = code
+        .render::<svg::Color>()
+        .min_dimensions(240, 240)
+        .dark_color(svg::Color("#000000"))
+        .light_color(svg::Color("#ffffff"))
+        .build();
+
+    (
+        [
+            (header::CONTENT_TYPE, "image/svg+xml"),
+            // Revoking must stop the code from showing, so never cache it
+            (header::CACHE_CONTROL, "no-store"),
+        ],
+        image,
+    )
+        .into_response()
+}
+
+/// The scheme and host links to this server should use: `PUBLIC_ORIGIN` when
+/// set, such as `https://jobs.example.com`. Otherwise the one the browser
+/// reached the server at, honoring `X-Forwarded-Proto` from a reverse proxy.
+/// Those headers come from the client, so set `PUBLIC_ORIGIN` in production.
+fn public_origin(headers: &HeaderMap) -> String {
+    if let Ok(origin) = std::env::var("PUBLIC_ORIGIN") {
+        let origin = origin.trim().trim_end_matches('/');
+        if !origin.is_empty() {
+            return origin.to_string();
+        }
+    }
+
+    let value = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+    let scheme = value("x-forwarded-proto").unwrap_or("http");
+    let host = value(header::HOST.as_str()).unwrap_or("localhost");
+
+    format!("{scheme}://{host}")
+}
+
+fn internal_error(e: impl std::fmt::Display) -> Response {
+    tracing::error!("API request failed: {e}");
+    StatusCode::INTERNAL_SERVER_ERROR.into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{extract::connect_info::MockConnectInfo, http::Request};
+    use std::net::SocketAddr;
+    use tower::ServiceExt;
+
+    fn app(pool: &SqlitePool, limiter: RateLimiter) -> Router {
+        router::<()>(pool.clone(), limiter)
+            .layer(MockConnectInfo(SocketAddr::from(([192, 0, 2, 1], 4000))))
+    }
+
+    async fn get_list(pool: &SqlitePool, if_none_match: Option<&HeaderValue>) -> Response {
+        let mut request = Request::get("/api/applications");
+        if let Some(etag) = if_none_match {
+            request = request.header(header::IF_NONE_MATCH, etag);
+        }
+        app(pool, RateLimiter::new(30, 60))
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[sqlx::test]
+    async fn only_the_public_endpoints_are_rate_limited(pool: SqlitePool) {
+        let app = app(&pool, RateLimiter::new(1, 1));
+        let status_of = |uri: String| {
+            let app = app.clone();
+            async move {
+                let request = Request::get(uri).body(Body::empty()).unwrap();
+                app.oneshot(request).await.unwrap().status()
+            }
+        };
+
+        assert_eq!(status_of("/api/applications".into()).await, StatusCode::OK);
+        assert_eq!(
+            status_of("/api/openapi.json".into()).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        // The gallery fetches every preview, well past any burst
+        for _ in 0..20 {
+            let preview = format!("/api/attachments/{}", Uuid::new_v4());
+            assert_eq!(status_of(preview).await, StatusCode::NOT_FOUND);
+        }
+    }
+
+    #[sqlx::test]
+    async fn list_answers_304_until_a_company_changes(pool: SqlitePool) {
+        let company = Uuid::new_v4().to_string();
+        sqlx::query(
+            "INSERT INTO companies (id, name, website, ceo, industry) VALUES (?, 'Acme', 'https://acme.example', '', 'Tech')",
+        )
+        .bind(&company)
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO applications (id, company_id, status, date, updated_at) VALUES (?, ?, 'Pending', ?, ?)",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(&company)
+        .bind(date_to_db(OffsetDateTime::now_utc()))
+        .bind(date_to_db(OffsetDateTime::now_utc()))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let first = get_list(&pool, None).await;
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first.headers()[header::ETAG].clone();
+
+        let unchanged = get_list(&pool, Some(&etag)).await;
+        assert_eq!(unchanged.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(unchanged.headers()[header::ETAG], etag);
+
+        // Renaming the company leaves the application's updated_at alone
+        sqlx::query("UPDATE companies SET name = 'Acme Corp' WHERE id = ?")
+            .bind(&company)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let renamed = get_list(&pool, Some(&etag)).await;
+        assert_eq!(renamed.status(), StatusCode::OK);
+        assert_ne!(renamed.headers()[header::ETAG], etag);
+    }
+}
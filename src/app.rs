@@ -1,13 +1,384 @@
 use leptos::{prelude::*, web_sys};
-use leptos_meta::{provide_meta_context, MetaTags, Stylesheet, Title};
+use leptos_meta::{provide_meta_context, Html, MetaTags, Stylesheet, Title};
 use leptos_router::{
-    components::{Route, Router, Routes},
-    StaticSegment,
+    components::{Route, Router, Routes, A},
+    hooks::{use_location, use_navigate, use_params_map, use_query_map},
+    location::Url,
+    params::ParamsMap,
+    NavigateOptions, ParamSegment, StaticSegment,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use time::{
+    format_description::BorrowedFormatItem, macros::format_description, Date, OffsetDateTime,
+};
 use uuid::Uuid;
 #[cfg(feature = "ssr")]
-use {sqlx::SqlitePool, time::OffsetDateTime};
+use {
+    sqlx::{QueryBuilder, Sqlite, SqlitePool},
+    time::format_description::well_known::Rfc3339,
+};
+
+/// How calendar dates such as follow-ups are written to the database and
+/// to date inputs.
+pub(crate) const DB_DATE: &[BorrowedFormatItem<'_>] = format_description!("[year]-[month]-[day]");
+
+/// How timestamps are written to the database: RFC3339 in UTC with a fixed
+/// number of subsecond digits, so they sort and compare correctly as text.
+#[cfg(feature = "ssr")]
+const DB_TIMESTAMP: &[BorrowedFormatItem<'_>] =
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:9]Z");
+
+/// Formats a timestamp for storing. Every timestamp written to the database
+/// goes through this, and is read back with [`date_from_db`].
+#[cfg(feature = "ssr")]
+pub(crate) fn date_to_db(dt: OffsetDateTime) -> String {
+    dt.to_offset(time::UtcOffset::UTC)
+        .format(DB_TIMESTAMP)
+        .expect("every UTC timestamp fits the database format")
+}
+
+/// Parses a stored timestamp. Older rows may carry a different offset or
+/// number of subsecond digits, so any RFC3339 timestamp is accepted.
+#[cfg(feature = "ssr")]
+pub(crate) fn date_from_db(s: &str) -> Result<OffsetDateTime, time::error::Parse> {
+    OffsetDateTime::parse(s, &Rfc3339)
+}
+
+/// A deadline this many days away or fewer is shown as urgent.
+const DEADLINE_URGENT_DAYS: i64 = 3;
+
+/// How far ahead the dashboard looks for postings about to close.
+#[cfg(feature = "ssr")]
+const DEADLINE_SOON_DAYS: i64 = 7;
+
+/// How long after being sent an application without a confirmation of
+/// receipt is flagged to verify it went through.
+#[cfg(feature = "ssr")]
+const VERIFY_SUBMISSION_DAYS: i64 = 3;
+
+/// Base score of a follow-up that is due, plus [`SCORE_PER_DAY`] for every
+/// day it is overdue. The weights keep each kind of action above the next.
+#[cfg(feature = "ssr")]
+const FOLLOW_UP_SCORE: i64 = 3000;
+
+/// Base score of a to-do application whose posting closes within
+/// [`DEADLINE_SOON_DAYS`], plus [`SCORE_PER_DAY`] for every day closer.
+#[cfg(feature = "ssr")]
+const DEADLINE_SCORE: i64 = 2000;
+
+/// Base score of a pending application that has not changed in
+/// [`STALE_PENDING_DAYS`], plus [`SCORE_PER_DAY`] for every further day.
+#[cfg(feature = "ssr")]
+const STALE_PENDING_SCORE: i64 = 1000;
+
+/// Base score of any other to-do application, plus [`SCORE_PER_DAY`] for
+/// every day since it was added.
+#[cfg(feature = "ssr")]
+const TO_DO_SCORE: i64 = 0;
+
+/// How much each day of urgency or neglect adds to a score, capped at
+/// [`MAX_DAYS_SCORED`] days so one kind of action cannot outrank another.
+#[cfg(feature = "ssr")]
+const SCORE_PER_DAY: i64 = 10;
+
+#[cfg(feature = "ssr")]
+const MAX_DAYS_SCORED: i64 = 90;
+
+/// A pending application unchanged for this many days is worth a nudge.
+#[cfg(feature = "ssr")]
+const STALE_PENDING_DAYS: i64 = 14;
+
+/// Describes how close the deadline of a to-do application is, as a label
+/// and the CSS class to show it with. Once applied, the deadline no longer
+/// matters and nothing is shown.
+fn deadline_badge(status: Status, deadline: Date, today: Date) -> Option<(String, &'static str)> {
+    if status != Status::ToDo {
+        return None;
+    }
+
+    let days = (deadline - today).whole_days();
+    let label = match days {
+        ..0 => return Some(("Deadline missed".to_string(), "deadline missed")),
+        0 => "Closes today".to_string(),
+        1 => "Closes tomorrow".to_string(),
+        days => format!("Closes in {days} days"),
+    };
+    let class = if days <= DEADLINE_URGENT_DAYS {
+        "deadline urgent"
+    } else {
+        "deadline"
+    };
+
+    Some((label, class))
+}
+
+/// Applications shown per page of the list.
+const PAGE_SIZE: u32 = 25;
+
+/// Events shown per page of the timeline.
+const TIMELINE_PAGE_SIZE: u32 = 50;
+
+/// Attachments shown per page of an application's gallery.
+const ATTACHMENTS_PAGE_SIZE: u32 = 12;
+
+/// How many items come before page `page` of `page_size` each, with pages
+/// counted from 1. Page 0 is taken as the first, and offsets too large for a
+/// `u32` stop at `u32::MAX`, which is past the last item anyway.
+fn page_offset(page: u32, page_size: u32) -> u32 {
+    (page.max(1) - 1).saturating_mul(page_size)
+}
+
+/// How many bytes all attachments together may take up.
+#[cfg(feature = "ssr")]
+pub(crate) const ATTACHMENT_QUOTA_BYTES: i64 = 100 * 1024 * 1024;
+
+/// What the list has selected for bulk operations, shared by the cards and
+/// the selection toolbar.
+#[derive(Clone, Copy, Default)]
+struct Selection {
+    /// Ids of the applications ticked on the current page.
+    ids: RwSignal<HashSet<Uuid>>,
+    /// Set when the user chose every application matching the filter, on
+    /// all pages. Bulk operations then send the filter instead of ids.
+    all_matching: RwSignal<bool>,
+}
+
+/// The status each card on the list currently shows, including changes still
+/// waiting on the server, so the dashboard can count them right away.
+#[derive(Clone, Copy, Default)]
+struct ShownStatuses(RwSignal<HashMap<Uuid, Status>>);
+
+/// Edits made on the list that the server has not confirmed yet. A refetch
+/// started by another action still returns the values from before them, so
+/// [`PendingEdits::reconcile`] lays them over whatever it loads.
+#[derive(Clone, Copy, Default)]
+struct PendingEdits {
+    edits: RwSignal<HashMap<Uuid, PendingEdit>>,
+    /// The application of the status change sent last. An action only
+    /// reports the result of its latest dispatch, so any result it reports
+    /// belongs to this one.
+    latest_status: StoredValue<Option<Uuid>>,
+}
+
+/// The fields of one application with an edit still on its way.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct PendingEdit {
+    status: Option<Status>,
+    /// When the server last changed the application before the edit, so a
+    /// later load can tell whether the edit has reached it since.
+    since: OffsetDateTime,
+}
+
+impl PendingEdit {
+    fn is_settled(&self) -> bool {
+        self.status.is_none()
+    }
+}
+
+impl PendingEdits {
+    fn status(self, id: Uuid) -> Option<Status> {
+        self.edits
+            .with(|edits| edits.get(&id).and_then(|edit| edit.status))
+    }
+
+    /// Records that `status` was sent for `id`, whose application the server
+    /// last changed at `since`.
+    fn set_status(self, id: Uuid, status: Status, since: OffsetDateTime) {
+        self.edits.update(|edits| {
+            edits
+                .entry(id)
+                .or_insert(PendingEdit {
+                    status: None,
+                    since,
+                })
+                .status = Some(status);
+        });
+        self.latest_status.set_value(Some(id));
+    }
+
+    /// Drops the pending status of `id` once the server has `status`, unless
+    /// the user picked another one in the meantime.
+    fn settle_status(self, id: Uuid, status: Status) {
+        self.edits.update(|edits| {
+            if let Some(edit) = edits.get_mut(&id) {
+                if edit.status == Some(status) {
+                    edit.status = None;
+                }
+                if edit.is_settled() {
+                    edits.remove(&id);
+                }
+            }
+        });
+    }
+
+    /// Drops the pending status of the change sent last, as it failed. Other
+    /// cards keep theirs.
+    fn discard_latest_status(self) {
+        let Some(id) = self.latest_status.get_value() else {
+            return;
+        };
+        self.edits.update(|edits| {
+            if let Some(edit) = edits.get_mut(&id) {
+                edit.status = None;
+                if edit.is_settled() {
+                    edits.remove(&id);
+                }
+            }
+        });
+    }
+
+    /// `application` as loaded, with the fields still being edited as the
+    /// user left them.
+    ///
+    /// Once the server has changed the application since the edit was made,
+    /// the loaded values win and the edit is forgotten. That settles changes
+    /// whose result was never reported, because another was sent after them.
+    fn reconcile(self, mut application: AllApplicationsResponse) -> AllApplicationsResponse {
+        let Some(edit) = self
+            .edits
+            .with_untracked(|edits| edits.get(&application.id).copied())
+        else {
+            return application;
+        };
+
+        if application.updated_at > edit.since {
+            // Untracked, as this runs while the list renders; the card is
+            // rebuilt from the loaded values anyway
+            self.edits.update_untracked(|edits| {
+                edits.remove(&application.id);
+            });
+        } else if let Some(status) = edit.status {
+            application.status = status;
+        }
+        application
+    }
+}
+
+impl Selection {
+    fn clear(self) {
+        self.ids.write().clear();
+        self.all_matching.set(false);
+    }
+}
+
+/// The user's preferences as stored, plus any change made since the page
+/// loaded. Changes apply immediately and are saved in the background.
+///
+/// Read them inside a `Suspense` that awaits `stored`.
+#[derive(Clone, Copy)]
+struct PreferencesContext {
+    stored: Resource<Result<Preferences, ServerFnError>>,
+    changed: RwSignal<Option<Preferences>>,
+    save: ServerAction<SavePreferences>,
+}
+
+impl PreferencesContext {
+    fn new() -> Self {
+        let save = ServerAction::<SavePreferences>::new();
+        toast_errors(save);
+
+        Self {
+            stored: Resource::new(|| (), |_| get_preferences()),
+            changed: RwSignal::new(None),
+            save,
+        }
+    }
+
+    fn get(self) -> Preferences {
+        self.changed
+            .get()
+            .or_else(|| self.stored.get().and_then(Result::ok))
+            .unwrap_or_default()
+    }
+
+    fn set(self, mut preferences: Preferences) {
+        // Saved with every change, so the server keeps up with daylight
+        // saving time as soon as anything is changed again
+        if let Some(quiet) = &mut preferences.quiet_hours {
+            quiet.utc_offset_minutes = local_utc_offset_minutes();
+        }
+        self.changed.set(Some(preferences.clone()));
+        self.save.dispatch(SavePreferences { preferences });
+    }
+}
+
+/// How long a toast stays on screen before dismissing itself.
+const TOAST_DURATION: std::time::Duration = std::time::Duration::from_secs(6);
+
+#[derive(Clone)]
+struct Toast {
+    id: u64,
+    message: String,
+}
+
+/// Short-lived error notifications, shown in the corner of every page.
+#[derive(Clone, Copy)]
+struct Toasts {
+    items: RwSignal<Vec<Toast>>,
+    next_id: StoredValue<u64>,
+}
+
+impl Toasts {
+    fn new() -> Self {
+        Self {
+            items: RwSignal::new(Vec::new()),
+            next_id: StoredValue::new(0),
+        }
+    }
+
+    fn push(self, message: String) {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        self.items.update(|items| items.push(Toast { id, message }));
+        set_timeout(move || self.dismiss(id), TOAST_DURATION);
+    }
+
+    fn dismiss(self, id: u64) {
+        self.items.update(|items| items.retain(|t| t.id != id));
+    }
+}
+
+/// Turns a failed server call into a message the user can act on: whether
+/// the request never made it, was rejected as invalid, or failed on the
+/// server. Input the server functions reject, like a bad date or website,
+/// comes back as [`ServerFnError::Args`] and is shown as is.
+fn describe_error(error: &ServerFnError) -> String {
+    match error {
+        ServerFnError::Request(_) => {
+            "You're offline or the server is unreachable. Please try again.".to_string()
+        }
+        ServerFnError::Args(msg) => format!("Invalid input: {msg}"),
+        ServerFnError::MissingArg(_) | ServerFnError::Serialization(_) => {
+            "Invalid input. Please check the form and try again.".to_string()
+        }
+        ServerFnError::Deserialization(_) | ServerFnError::Response(_) => {
+            "The server sent an unexpected response. Try reloading the page.".to_string()
+        }
+        ServerFnError::ServerError(msg)
+        | ServerFnError::MiddlewareError(msg)
+        | ServerFnError::Registration(msg) => {
+            format!("Something went wrong on the server: {msg}")
+        }
+        _ => format!("Something went wrong on the server: {error}"),
+    }
+}
+
+/// Shows a toast whenever `action` finishes with an error.
+fn toast_errors<S>(action: ServerAction<S>)
+where
+    S: leptos::server_fn::ServerFn<Error = ServerFnError> + Send + Sync + Clone + 'static,
+    S::Output: Send + Sync + 'static,
+{
+    let toasts = expect_context::<Toasts>();
+    Effect::new(move |_| {
+        action.value().with(|value| {
+            if let Some(Err(e)) = value {
+                toasts.push(describe_error(e));
+            }
+        })
+    });
+}
 
 pub fn shell(options: LeptosOptions) -> impl IntoView {
     view! {
@@ -33,6 +404,10 @@ pub fn shell(options: LeptosOptions) -> impl IntoView {
 pub fn App() -> impl IntoView {
     // Provides context that manages stylesheets, titles, meta tags, etc.
     provide_meta_context();
+    provide_context(Toasts::new());
+
+    let preferences = PreferencesContext::new();
+    provide_context(preferences);
 
     view! {
         // injects a stylesheet into the document <head>
@@ -42,58 +417,348 @@ pub fn App() -> impl IntoView {
         // sets the document title
         <Title text="Welcome to Leptos" />
 
+        <Html {..} class=move || preferences.get().root_class() />
+
         // content for this welcome page
         <Router>
+            <nav class="site-nav">
+                <A href="/" exact=true>"Applications"</A>
+                <A href="/today">"Today"</A>
+                <A href="/timeline">"Timeline"</A>
+                <A href="/maintenance">"Maintenance"</A>
+                <A href="/settings">"Settings"</A>
+            </nav>
             <main class="container">
                 <Routes fallback=|| "Page not found.".into_view()>
                     <Route path=StaticSegment("") view=HomePage />
+                    <Route
+                        path=(StaticSegment("applications"), ParamSegment("id"))
+                        view=ApplicationDetail
+                    />
+                    <Route
+                        path=(StaticSegment("share"), ParamSegment("token"))
+                        view=SharedApplicationPage
+                    />
+                    <Route path=StaticSegment("compare") view=ComparePage />
+                    <Route path=StaticSegment("today") view=TodayPage />
+                    <Route path=StaticSegment("timeline") view=TimelinePage />
+                    <Route path=StaticSegment("maintenance") view=MaintenancePage />
+                    <Route path=StaticSegment("settings") view=SettingsPage />
                 </Routes>
             </main>
+            <ToastHost />
+            <ReminderNotifier />
         </Router>
     }
 }
 
+#[component]
+fn ToastHost() -> impl IntoView {
+    let toasts = expect_context::<Toasts>();
+
+    view! {
+        <div class="toast-host" role="status" aria-live="polite">
+            <For each=move || toasts.items.get() key=|toast| toast.id let:toast>
+                <div class="toast" on:click=move |_| toasts.dismiss(toast.id)>
+                    {toast.message}
+                </div>
+            </For>
+        </div>
+    }
+}
+
+/// How often an open app checks for reminders that fell due.
+const REMINDER_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Toasts each reminder once as it falls due, for as long as the app is
+/// open. During the quiet hours of the preferences reminders are held back;
+/// as they are still due afterwards, the first check once the hours are over
+/// shows them all.
+#[component]
+fn ReminderNotifier() -> impl IntoView {
+    let toasts = expect_context::<Toasts>();
+    let preferences = expect_context::<PreferencesContext>();
+    let notified = StoredValue::new(HashSet::<Uuid>::new());
+
+    let pathname = use_location().pathname;
+    let check = move || {
+        // Visitors of a shared application don't get the owner's reminders
+        if pathname.get_untracked().starts_with("/share/") {
+            return;
+        }
+        let quiet_hours = untrack(move || preferences.get().quiet_hours);
+        if quiet_hours.is_some_and(|quiet| quiet.contains(local_hour())) {
+            return;
+        }
+        leptos::task::spawn_local(async move {
+            // A failed check is simply tried again on the next one
+            let Ok(due) = get_due_reminders().await else {
+                return;
+            };
+            for due in due {
+                let id = due.reminder.id;
+                if notified.with_value(|notified| notified.contains(&id)) {
+                    continue;
+                }
+                notified.update_value(|notified| {
+                    notified.insert(id);
+                });
+                toasts.push(format!(
+                    "Reminder for {}: {}",
+                    due.company, due.reminder.text
+                ));
+            }
+        });
+    };
+    // Effects only run in the browser, so the server never polls. Checks
+    // wait for the preferences, or quiet hours would not apply on page load.
+    Effect::new(move |_| {
+        if preferences.stored.with(Option::is_none) {
+            return;
+        }
+        check();
+        if let Ok(handle) = set_interval_with_handle(check, REMINDER_CHECK_INTERVAL) {
+            on_cleanup(move || handle.clear());
+        }
+    });
+}
+
+/// The hour of the day where the browser is, or in UTC when its offset is
+/// unknown.
+fn local_hour() -> u8 {
+    OffsetDateTime::now_local()
+        .unwrap_or_else(|_| OffsetDateTime::now_utc())
+        .hour()
+}
+
+/// How far the browser's clock is ahead of UTC, in minutes, or 0 when its
+/// offset is unknown.
+fn local_utc_offset_minutes() -> i16 {
+    time::UtcOffset::current_local_offset().map_or(0, |offset| offset.whole_minutes())
+}
+
+/// Loads one page of the applications matching `filter`, counting from 1,
+/// along with how many match in total.
 #[server]
 #[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
-async fn get_all_applications() -> Result<Vec<AllApplicationsResponse>, ServerFnError> {
+async fn get_all_applications(
+    filter: ApplicationFilter,
+    page: u32,
+) -> Result<ApplicationPage, ServerFnError> {
     let pool = expect_context::<SqlitePool>();
 
-    let rows: Vec<ApplicationRow> = sqlx::query_as(
+    let mut count = QueryBuilder::<Sqlite>::new(
         r#"
-        SELECT a.id, a.status, a.date,
-               c.id as company_id, c.name, c.website, c.ceo, c.industry
-        FROM applications a
+        SELECT COUNT(*) FROM applications a
         JOIN companies c ON a.company_id = c.id
-        ORDER BY a.date DESC
+        WHERE a.archived_at IS NULL
         "#,
+    );
+    push_filter_conditions(&mut count, &filter);
+    let total: u32 = count
+        .build_query_scalar()
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to count applications: {e}")))?;
+
+    let page = page.max(1);
+    let mut query = filtered_applications(&filter);
+    query
+        .push(" LIMIT ")
+        .push_bind(PAGE_SIZE)
+        .push(" OFFSET ")
+        .push_bind(page_offset(page, PAGE_SIZE));
+
+    let rows: Vec<ApplicationRow> = query
+        .build_query_as()
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to fetch applications: {e}")))?;
+
+    Ok(ApplicationPage {
+        applications: rows
+            .into_iter()
+            .map(TryFrom::try_from)
+            .collect::<Result<_, _>>()?,
+        page,
+        total,
+    })
+}
+
+/// Selects the columns of an [`ApplicationRow`] from `applications a` joined
+/// with `companies c`.
+#[cfg(feature = "ssr")]
+const SELECT_APPLICATIONS: &str = r#"
+    SELECT a.id, a.status, a.date, a.updated_at, a.follow_up, a.deadline,
+           a.notes != '' AS has_notes,
+           c.id as company_id, c.name, c.website, c.ceo, c.industry
+    FROM applications a
+    JOIN companies c ON a.company_id = c.id
+"#;
+
+#[cfg(feature = "ssr")]
+pub(crate) async fn fetch_applications(
+    pool: &SqlitePool,
+    filter: &ApplicationFilter,
+) -> Result<Vec<AllApplicationsResponse>, ServerFnError> {
+    let rows: Vec<ApplicationRow> = filtered_applications(filter)
+        .build_query_as()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to fetch applications: {e}")))?;
+
+    rows.into_iter().map(TryFrom::try_from).collect()
+}
+
+/// Builds the sorted query for the live applications matching `filter`.
+#[cfg(feature = "ssr")]
+fn filtered_applications(filter: &ApplicationFilter) -> QueryBuilder<'_, Sqlite> {
+    let mut query =
+        QueryBuilder::<Sqlite>::new(format!("{SELECT_APPLICATIONS} WHERE a.archived_at IS NULL"));
+    push_filter_conditions(&mut query, filter);
+    query.push(" ORDER BY ").push(filter.sort.order_by());
+    query
+}
+
+/// Appends an `AND ...` clause for every criterion set on `filter`.
+#[cfg(feature = "ssr")]
+fn push_filter_conditions(query: &mut QueryBuilder<'_, Sqlite>, filter: &ApplicationFilter) {
+    if let Some(status) = filter.status {
+        query.push(" AND a.status = ").push_bind(status.as_str());
+    }
+    if let Some(search) = &filter.search {
+        let pattern = format!(
+            "%{}%",
+            search
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_")
+        );
+        query
+            .push(" AND (c.name LIKE ")
+            .push_bind(pattern.clone())
+            .push(" ESCAPE '\\' OR c.industry LIKE ")
+            .push_bind(pattern.clone())
+            .push(" ESCAPE '\\'");
+        if filter.search_notes {
+            query
+                .push(" OR a.notes LIKE ")
+                .push_bind(pattern)
+                .push(" ESCAPE '\\'");
+        }
+        query.push(")");
+    }
+    // Dates are stored with a leading YYYY-MM-DD, so comparing that prefix is enough
+    if let Some(from) = &filter.from {
+        query
+            .push(" AND substr(a.date, 1, 10) >= ")
+            .push_bind(from.clone());
+    }
+    if let Some(to) = &filter.to {
+        query
+            .push(" AND substr(a.date, 1, 10) <= ")
+            .push_bind(to.clone());
+    }
+}
+
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_preferences() -> Result<Preferences, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    load_preferences(&pool).await
+}
+
+/// Reads the stored preferences, falling back to the defaults when none have
+/// been saved yet. Settings missing from older rows take their defaults too.
+#[cfg(feature = "ssr")]
+pub(crate) async fn load_preferences(pool: &SqlitePool) -> Result<Preferences, ServerFnError> {
+    let data: Option<String> = sqlx::query_scalar("SELECT data FROM preferences WHERE id = 1")
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to fetch preferences: {e}")))?;
+
+    data.map_or_else(
+        || Ok(Preferences::default()),
+        |data| serde_json::from_str(&data).map_err(|e| ServerFnError::new(e.to_string())),
+    )
+}
+
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn save_preferences(preferences: Preferences) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    sqlx::query(
+        "INSERT INTO preferences (id, data) VALUES (1, ?) ON CONFLICT(id) DO UPDATE SET data = excluded.data",
+    )
+    .bind(serde_json::to_string(&preferences)?)
+    .execute(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to save preferences: {e}")))?;
+
+    Ok(())
+}
+
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_filter_presets() -> Result<Vec<FilterPreset>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let rows: Vec<FilterPresetRow> = sqlx::query_as(
+        "SELECT id, name, status, search, search_notes, sort, date_from, date_to FROM filter_presets ORDER BY name",
     )
     .fetch_all(&pool)
     .await
-    .map_err(|e| ServerFnError::new(format!("Failed to fetch applications: {e}")))?;
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch filter presets: {e}")))?;
 
     rows.into_iter().map(TryFrom::try_from).collect()
 }
 
+/// Saves `filter` under `name`, replacing any preset that already uses that name.
 #[server]
-#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(application_id = %id)))]
-async fn delete_application(id: Uuid) -> Result<(), ServerFnError> {
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn save_filter_preset(name: String, filter: ApplicationFilter) -> Result<(), ServerFnError> {
     let pool = expect_context::<SqlitePool>();
 
-    sqlx::query("DELETE FROM applications WHERE id = ?")
-        .bind(id.to_string())
-        .execute(&pool)
-        .await?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ServerFnError::new("Preset name cannot be empty"));
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO filter_presets (id, name, status, search, search_notes, sort, date_from, date_to)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT(name) DO UPDATE SET
+            status = excluded.status,
+            search = excluded.search,
+            search_notes = excluded.search_notes,
+            sort = excluded.sort,
+            date_from = excluded.date_from,
+            date_to = excluded.date_to
+        "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(name)
+    .bind(filter.status.map(|s| s.as_str()))
+    .bind(filter.search)
+    .bind(filter.search_notes)
+    .bind(filter.sort.as_str())
+    .bind(filter.from)
+    .bind(filter.to)
+    .execute(&pool)
+    .await?;
 
     Ok(())
 }
 
 #[server]
-#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(application_id = %id, new_status = %status.as_str())))]
-async fn update_application_status(id: Uuid, status: Status) -> Result<(), ServerFnError> {
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(preset_id = %id)))]
+async fn delete_filter_preset(id: Uuid) -> Result<(), ServerFnError> {
     let pool = expect_context::<SqlitePool>();
 
-    sqlx::query("UPDATE applications SET status = ? WHERE id = ?")
-        .bind(status.as_str())
+    sqlx::query("DELETE FROM filter_presets WHERE id = ?")
         .bind(id.to_string())
         .execute(&pool)
         .await?;
@@ -102,152 +767,3939 @@ async fn update_application_status(id: Uuid, status: Status) -> Result<(), Serve
 }
 
 #[server]
-#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, skip(req), fields(company = %req.company.name)))]
-async fn create_application(req: CreateApplicationRequest) -> Result<(), ServerFnError> {
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(application_id = %id)))]
+async fn get_application_detail(id: Uuid) -> Result<ApplicationDetailResponse, ServerFnError> {
     let pool = expect_context::<SqlitePool>();
+    let mut conn = pool.acquire().await?;
 
-    let company = Company::new(
-        req.company.name,
-        req.company.website,
-        req.company.ceo,
-        req.company.industry,
-    );
-    let application = Application::new(&company, req.status);
+    let row: Option<ApplicationRow> =
+        sqlx::query_as(&format!("{SELECT_APPLICATIONS} WHERE a.id = ?"))
+            .bind(id.to_string())
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to fetch application: {e}")))?;
 
-    insert_application(&pool, &application).await
-}
+    let application = row
+        .ok_or_else(|| ServerFnError::new(format!("Application {id} not found")))?
+        .try_into()?;
 
-#[cfg(feature = "ssr")]
-async fn insert_application(
-    pool: &SqlitePool,
-    application: &Application,
-) -> Result<(), ServerFnError> {
-    let mut tx = pool.begin().await?;
+    let (notes, confirmation_received): (String, Option<bool>) =
+        sqlx::query_as("SELECT notes, confirmation_received FROM applications WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to fetch notes: {e}")))?;
 
-    sqlx::query("INSERT INTO companies (id, name, website, ceo, industry) VALUES (?, ?, ?, ?, ?)")
-        .bind(application.company.id.to_string())
-        .bind(&application.company.name)
-        .bind(&application.company.website)
-        .bind(&application.company.ceo)
-        .bind(&application.company.industry)
-        .execute(&mut *tx)
-        .await?;
+    let history: Vec<StatusHistoryRow> = sqlx::query_as(
+        "SELECT status, changed_at, reason FROM status_history WHERE application_id = ? ORDER BY id",
+    )
+    .bind(id.to_string())
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch status history: {e}")))?;
 
-    sqlx::query("INSERT INTO applications (id, company_id, status, date) VALUES (?, ?, ?, ?)")
-        .bind(application.id.to_string())
-        .bind(application.company.id.to_string())
-        .bind(application.status.as_str())
-        .bind(application.date.to_string())
-        .execute(&mut *tx)
-        .await?;
+    let attachments = fetch_attachment_page(&mut conn, id, ATTACHMENTS_PAGE_SIZE, 0).await?;
 
-    tx.commit().await?;
-    Ok(())
+    Ok(ApplicationDetailResponse {
+        application,
+        history: history
+            .into_iter()
+            .map(TryFrom::try_from)
+            .collect::<Result<_, _>>()?,
+        notes,
+        confirmation_received,
+        attachments,
+    })
 }
 
-/// Renders the home page of your application.
-#[component]
-fn HomePage() -> impl IntoView {
-    let delete = ServerAction::<DeleteApplication>::new();
-    let create = ServerMultiAction::<CreateApplication>::new();
-    let update_status = ServerAction::<UpdateApplicationStatus>::new();
+/// How many related applications the detail page suggests at most.
+#[cfg(feature = "ssr")]
+const SIMILAR_APPLICATIONS_LIMIT: i64 = 5;
 
-    provide_context(Resource::new(
-        move || {
-            (
-                delete.version().get(),
-                create.version().get(),
-                update_status.version().get(),
-            )
-        },
-        |_| get_all_applications(),
-    ));
-    provide_context(create);
-    provide_context(delete);
-    provide_context(update_status);
+/// Returns the most recent other applications whose company is in the same
+/// industry as application `id`. Applications without an industry have none.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(application_id = %id)))]
+async fn get_similar_applications(id: Uuid) -> Result<Vec<AllApplicationsResponse>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
 
-    view! {
-        <h1>"Job Applications"</h1>
-        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
-            <ApplicationList />
-        </Suspense>
-    }
+    let rows: Vec<ApplicationRow> = sqlx::query_as(&format!(
+        r#"
+        {SELECT_APPLICATIONS}
+        JOIN applications cur ON cur.id = ?
+        JOIN companies cur_c ON cur.company_id = cur_c.id
+        WHERE a.id != cur.id
+          AND a.archived_at IS NULL
+          AND trim(cur_c.industry) != ''
+          AND lower(trim(c.industry)) = lower(trim(cur_c.industry))
+        ORDER BY a.date DESC
+        LIMIT ?
+        "#
+    ))
+    .bind(id.to_string())
+    .bind(SIMILAR_APPLICATIONS_LIMIT)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch similar applications: {e}")))?;
+
+    rows.into_iter().map(TryFrom::try_from).collect()
 }
 
-#[component]
-fn ApplicationList() -> impl IntoView {
-    let applications =
-        expect_context::<Resource<Result<Vec<AllApplicationsResponse>, ServerFnError>>>();
+/// Returns up to `limit` attachments of application `application_id`, newest
+/// first, skipping the first `offset`, together with how much of the quota
+/// all attachments use. `limit` is capped at [`ATTACHMENTS_PAGE_SIZE`].
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn list_attachments(
+    application_id: Uuid,
+    limit: u32,
+    offset: u32,
+) -> Result<AttachmentPage, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut conn = pool.acquire().await?;
 
-    view! {
-        <CreateApplicationForm />
-        <div class="application-list">
-            <div class="list-header">
-                <span>"Company"</span>
-                <span>"Industry"</span>
-                <span>"Link"</span>
-                <span>"Status"</span>
-                <span>"Action"</span>
-            </div>
-            <Suspense fallback=|| ()>
-                {move || Suspend::new(async move {
-                    match applications.await {
-                        Ok(data) => {
-                            view! {
-                                <For each=move || data.clone() key=|s| s.id let:application>
-                                    <ApplicationCard application />
-                                </For>
-                            }
-                                .into_any()
-                        }
-                        Err(_) => {
-                            view! {
-                                <div class="error">"Error loading applications"</div>
-                            }
-                                .into_any()
-                        }
-                    }
-                })}
-            </Suspense>
-        </div>
+    fetch_attachment_page(&mut conn, application_id, limit, offset).await
+}
+
+/// Loads a page of attachments for [`list_attachments`] and
+/// [`get_application_detail`].
+#[cfg(feature = "ssr")]
+async fn fetch_attachment_page(
+    conn: &mut sqlx::SqliteConnection,
+    application_id: Uuid,
+    limit: u32,
+    offset: u32,
+) -> Result<AttachmentPage, ServerFnError> {
+    let limit = limit.min(ATTACHMENTS_PAGE_SIZE);
+
+    let (total, used_bytes): (u32, i64) = sqlx::query_as(
+        r#"
+        SELECT (SELECT COUNT(*) FROM attachments WHERE application_id = ?),
+               (SELECT COALESCE(SUM(size), 0) FROM attachments)
+        "#,
+    )
+    .bind(application_id.to_string())
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to count attachments: {e}")))?;
+
+    // The file contents stay in the database; previews fetch them separately
+    let rows: Vec<AttachmentRow> = sqlx::query_as(
+        r#"
+        SELECT id, file_name, content_type, size, uploaded_at FROM attachments
+        WHERE application_id = ?
+        ORDER BY uploaded_at DESC, id
+        LIMIT ? OFFSET ?
+        "#,
+    )
+    .bind(application_id.to_string())
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch attachments: {e}")))?;
+
+    Ok(AttachmentPage {
+        attachments: rows
+            .into_iter()
+            .map(TryFrom::try_from)
+            .collect::<Result<_, _>>()?,
+        total,
+        used_bytes,
+        quota_bytes: ATTACHMENT_QUOTA_BYTES,
+    })
+}
+
+/// Returns the token of the live share link of application `application_id`,
+/// if it has one.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_share_link(application_id: Uuid) -> Result<Option<String>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    sqlx::query_scalar(
+        "SELECT token FROM share_links WHERE application_id = ? AND revoked_at IS NULL",
+    )
+    .bind(application_id.to_string())
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch share link: {e}")))
+}
+
+/// Creates a public, read-only link to application `application_id` and
+/// returns its token. An application has at most one live link, which is
+/// returned again if it exists.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn create_share_link(application_id: Uuid) -> Result<String, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut tx = pool.begin().await?;
+
+    let existing: Option<String> = sqlx::query_scalar(
+        "SELECT token FROM share_links WHERE application_id = ? AND revoked_at IS NULL",
+    )
+    .bind(application_id.to_string())
+    .fetch_optional(&mut *tx)
+    .await?;
+    if let Some(token) = existing {
+        return Ok(token);
+    }
+
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM applications WHERE id = ?)")
+        .bind(application_id.to_string())
+        .fetch_one(&mut *tx)
+        .await?;
+    if !exists {
+        return Err(ServerFnError::new(format!(
+            "Application {application_id} not found"
+        )));
     }
+
+    let token = Uuid::new_v4().simple().to_string();
+    sqlx::query("INSERT INTO share_links (token, application_id, created_at) VALUES (?, ?, ?)")
+        .bind(&token)
+        .bind(application_id.to_string())
+        .bind(date_to_db(OffsetDateTime::now_utc()))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to create share link: {e}")))?;
+
+    tx.commit().await?;
+    Ok(token)
 }
 
-#[component]
-fn ApplicationCard(application: AllApplicationsResponse) -> impl IntoView {
-    let delete_action = expect_context::<ServerAction<DeleteApplication>>();
-    let update_status_action = expect_context::<ServerAction<UpdateApplicationStatus>>();
+/// Revokes share link `token`, so it and its QR code stop working.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn revoke_share_link(token: String) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
 
-    let id = application.id;
-    let status = RwSignal::new(application.status);
+    sqlx::query("UPDATE share_links SET revoked_at = ? WHERE token = ? AND revoked_at IS NULL")
+        .bind(date_to_db(OffsetDateTime::now_utc()))
+        .bind(token)
+        .execute(&pool)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to revoke share link: {e}")))?;
 
-    let on_status_change = move |ev: web_sys::Event| {
-        let target = event_target::<web_sys::HtmlSelectElement>(&ev);
-        if let Ok(new_status) = target.value().parse::<Status>() {
-            status.set(new_status);
-            update_status_action.dispatch(UpdateApplicationStatus {
-                id,
-                status: new_status,
-            });
+    Ok(())
+}
+
+/// Lists the reminders of application `application_id`, open ones first and
+/// each group soonest first.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn list_reminders(application_id: Uuid) -> Result<Vec<Reminder>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let rows: Vec<ReminderRow> = sqlx::query_as(
+        r#"
+        SELECT id, application_id, remind_at, text, done FROM reminders
+        WHERE application_id = ?
+        ORDER BY done, remind_at, id
+        "#,
+    )
+    .bind(application_id.to_string())
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch reminders: {e}")))?;
+
+    rows.into_iter().map(TryFrom::try_from).collect()
+}
+
+/// Adds a reminder to application `application_id`, due on `remind_at`.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, skip(text)))]
+async fn add_reminder(
+    application_id: Uuid,
+    remind_at: Date,
+    text: String,
+) -> Result<Reminder, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let text = reminder_text(&text)?;
+
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM applications WHERE id = ?)")
+        .bind(application_id.to_string())
+        .fetch_one(&pool)
+        .await?;
+    if !exists {
+        return Err(ServerFnError::new(format!(
+            "Application {application_id} not found"
+        )));
+    }
+
+    let reminder = Reminder {
+        id: Uuid::new_v4(),
+        application_id,
+        remind_at,
+        text,
+        done: false,
+    };
+    sqlx::query(
+        "INSERT INTO reminders (id, application_id, remind_at, text, done) VALUES (?, ?, ?, ?, 0)",
+    )
+    .bind(reminder.id.to_string())
+    .bind(application_id.to_string())
+    .bind(remind_at.format(DB_DATE)?)
+    .bind(&reminder.text)
+    .execute(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to add reminder: {e}")))?;
+
+    Ok(reminder)
+}
+
+/// Changes when reminder `id` is due and what it says.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, skip(text)))]
+async fn update_reminder(id: Uuid, remind_at: Date, text: String) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let text = reminder_text(&text)?;
+
+    let updated = sqlx::query("UPDATE reminders SET remind_at = ?, text = ? WHERE id = ?")
+        .bind(remind_at.format(DB_DATE)?)
+        .bind(text)
+        .bind(id.to_string())
+        .execute(&pool)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to update reminder: {e}")))?
+        .rows_affected();
+    if updated == 0 {
+        return Err(ServerFnError::new(format!("Reminder {id} not found")));
+    }
+
+    Ok(())
+}
+
+/// Marks reminder `id` as done, or open again.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn set_reminder_done(id: Uuid, done: bool) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let updated = sqlx::query("UPDATE reminders SET done = ? WHERE id = ?")
+        .bind(done)
+        .bind(id.to_string())
+        .execute(&pool)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to update reminder: {e}")))?
+        .rows_affected();
+    if updated == 0 {
+        return Err(ServerFnError::new(format!("Reminder {id} not found")));
+    }
+
+    Ok(())
+}
+
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn delete_reminder(id: Uuid) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let deleted = sqlx::query("DELETE FROM reminders WHERE id = ?")
+        .bind(id.to_string())
+        .execute(&pool)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to delete reminder: {e}")))?
+        .rows_affected();
+    if deleted == 0 {
+        return Err(ServerFnError::new(format!("Reminder {id} not found")));
+    }
+
+    Ok(())
+}
+
+/// Lists the open reminders due today or earlier on active applications,
+/// oldest first.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_due_reminders() -> Result<Vec<DueReminder>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let today = OffsetDateTime::now_utc().date();
+
+    let rows: Vec<DueReminderRow> = sqlx::query_as(
+        r#"
+        SELECT r.id, r.application_id, r.remind_at, r.text, r.done, c.name AS company
+        FROM reminders r
+        JOIN applications a ON r.application_id = a.id
+        JOIN companies c ON a.company_id = c.id
+        WHERE r.done = 0 AND r.remind_at <= ? AND a.archived_at IS NULL
+        ORDER BY r.remind_at, c.name
+        "#,
+    )
+    .bind(today.format(DB_DATE)?)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch due reminders: {e}")))?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(DueReminder {
+                reminder: row.reminder.try_into()?,
+                company: row.company,
+            })
+        })
+        .collect()
+}
+
+/// The trimmed text of a reminder, which must say something.
+#[cfg(feature = "ssr")]
+fn reminder_text(text: &str) -> Result<String, ServerFnError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(ServerFnError::new("A reminder needs some text"));
+    }
+    Ok(text.to_string())
+}
+
+/// Whether `token` belongs to a share link that has not been revoked.
+#[cfg(feature = "ssr")]
+pub(crate) async fn share_link_is_live(
+    pool: &SqlitePool,
+    token: &str,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM share_links WHERE token = ? AND revoked_at IS NULL)",
+    )
+    .bind(token)
+    .fetch_one(pool)
+    .await
+}
+
+/// Loads what a share link shows of its application. Revoked and unknown
+/// links get the same error, so tokens cannot be probed.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, skip(token)))]
+pub(crate) async fn get_shared_application(
+    token: String,
+) -> Result<SharedApplication, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let row: Option<ApplicationRow> = sqlx::query_as(&format!(
+        r#"
+        {SELECT_APPLICATIONS}
+        JOIN share_links s ON s.application_id = a.id
+        WHERE s.token = ? AND s.revoked_at IS NULL
+        "#
+    ))
+    .bind(token)
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch application: {e}")))?;
+
+    let application = AllApplicationsResponse::try_from(
+        row.ok_or_else(|| ServerFnError::new("This link has been revoked or does not exist"))?,
+    )?;
+
+    Ok(SharedApplication {
+        company: application.company.name,
+        industry: application.company.industry,
+        website: application.company.website,
+        status: application.status,
+        date: application.date,
+    })
+}
+
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(application_id = %id)))]
+async fn delete_application(id: String) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let id = parse_application_id(&id)?;
+
+    let deleted = sqlx::query("DELETE FROM applications WHERE id = ?")
+        .bind(id.to_string())
+        .execute(&pool)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to delete application: {e}")))?
+        .rows_affected();
+    if deleted == 0 {
+        return Err(ServerFnError::new(format!("Application {id} not found")));
+    }
+
+    Ok(())
+}
+
+/// Parses an application id taken from form input, so a mangled id is
+/// reported as such instead of as a generic argument error.
+#[cfg(feature = "ssr")]
+fn parse_application_id(id: &str) -> Result<Uuid, ServerFnError> {
+    Uuid::parse_str(id.trim())
+        .map_err(|_| ServerFnError::Args(format!("Invalid application id: {id:?}")))
+}
+
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(application_id = %id, new_status = %status.as_str())))]
+async fn update_application_status(
+    id: String,
+    status: Status,
+) -> Result<StatusChangeEvent, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let id = parse_application_id(&id)?;
+    let offer_mode = if status == Status::Accepted {
+        load_preferences(&pool).await?.accepted_offer
+    } else {
+        AcceptedOfferMode::Off
+    };
+    let mut tx = pool.begin().await?;
+
+    let previous: Option<String> =
+        sqlx::query_scalar("SELECT status FROM applications WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to fetch application: {e}")))?;
+    let previous: Status = previous
+        .ok_or_else(|| ServerFnError::new(format!("Application {id} not found")))?
+        .parse()
+        .map_err(|e: String| ServerFnError::new(e))?;
+
+    let now = OffsetDateTime::now_utc();
+    for dates in load_application_dates(&mut tx, &[id]).await? {
+        validate_application_dates(
+            &ApplicationDates {
+                updated_at: now,
+                ..dates
+            },
+            now,
+        )?;
+    }
+
+    sqlx::query("UPDATE applications SET status = ?, updated_at = ? WHERE id = ?")
+        .bind(status.as_str())
+        .bind(date_to_db(now))
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to update status: {e}")))?;
+
+    record_status_change(&mut tx, id, status, now, None).await?;
+    if status == Status::Solicitated && previous != Status::Solicitated {
+        await_confirmation(&mut tx, &[id]).await?;
+    }
+
+    let mut withdrawn = 0;
+    let mut withdrawable = 0;
+    if status == Status::Accepted && previous != Status::Accepted {
+        match offer_mode {
+            AcceptedOfferMode::Off => {}
+            AcceptedOfferMode::Ask => withdrawable = count_other_active(&mut tx, id).await?,
+            AcceptedOfferMode::Auto => withdrawn = withdraw_other_active(&mut tx, id, now).await?,
+        }
+    }
+
+    tx.commit().await?;
+    Ok(StatusChangeEvent {
+        id,
+        previous,
+        status,
+        updated_at: now,
+        withdrawn,
+        withdrawable,
+    })
+}
+
+/// Why applications withdrawn by [`withdraw_other_active`] left the running.
+#[cfg(feature = "ssr")]
+const ACCEPTED_ELSEWHERE: &str = "accepted offer elsewhere";
+
+/// The statuses that leave an application out of the running once another
+/// offer is accepted: not yet applied to, already accepted, or over.
+#[cfg(feature = "ssr")]
+const NOT_IN_THE_RUNNING: [Status; 4] = [
+    Status::ToDo,
+    Status::Accepted,
+    Status::Rejected,
+    Status::Withdrawn,
+];
+
+/// Appends the live applications besides `accepted_id` that are still in the
+/// running, and so are given up once that one's offer is accepted.
+#[cfg(feature = "ssr")]
+fn push_other_active(query: &mut QueryBuilder<'_, Sqlite>, accepted_id: Uuid) {
+    query
+        .push("archived_at IS NULL AND id != ")
+        .push_bind(accepted_id.to_string())
+        .push(" AND status NOT IN (");
+    let mut separated = query.separated(", ");
+    for status in NOT_IN_THE_RUNNING {
+        separated.push_bind(status.as_str());
+    }
+    separated.push_unseparated(")");
+}
+
+#[cfg(feature = "ssr")]
+async fn count_other_active(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    accepted_id: Uuid,
+) -> Result<u32, ServerFnError> {
+    let mut query = QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM applications WHERE ");
+    push_other_active(&mut query, accepted_id);
+    let count: i64 = query
+        .build_query_scalar()
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to count applications: {e}")))?;
+
+    Ok(count as u32)
+}
+
+/// Moves every other active application to Withdrawn, recording why in
+/// their history.
+#[cfg(feature = "ssr")]
+async fn withdraw_other_active(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    accepted_id: Uuid,
+    now: OffsetDateTime,
+) -> Result<u32, ServerFnError> {
+    let mut query = QueryBuilder::<Sqlite>::new("SELECT id FROM applications WHERE ");
+    push_other_active(&mut query, accepted_id);
+    let ids: Vec<String> = query
+        .build_query_scalar()
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to fetch applications: {e}")))?;
+
+    let mut query = QueryBuilder::<Sqlite>::new("UPDATE applications SET status = ");
+    query
+        .push_bind(Status::Withdrawn.as_str())
+        .push(", updated_at = ")
+        .push_bind(date_to_db(now))
+        .push(" WHERE ");
+    push_other_active(&mut query, accepted_id);
+    query
+        .build()
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to withdraw applications: {e}")))?;
+
+    for id in &ids {
+        let id = parse_application_id(id)?;
+        record_status_change(tx, id, Status::Withdrawn, now, Some(ACCEPTED_ELSEWHERE)).await?;
+    }
+
+    Ok(ids.len() as u32)
+}
+
+/// Withdraws every other active application now that the offer of
+/// `accepted_id` has been accepted, returning how many were withdrawn.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(accepted_id = %accepted_id)))]
+async fn withdraw_other_applications(accepted_id: Uuid) -> Result<u32, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut tx = pool.begin().await?;
+
+    let status: Option<String> =
+        sqlx::query_scalar("SELECT status FROM applications WHERE id = ? AND archived_at IS NULL")
+            .bind(accepted_id.to_string())
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to fetch application: {e}")))?;
+    match status {
+        None => {
+            return Err(ServerFnError::new(format!(
+                "Application {accepted_id} not found"
+            )))
         }
+        Some(status) if status != Status::Accepted.as_str() => {
+            return Err(ServerFnError::new(format!(
+                "Application {accepted_id} has not been accepted"
+            )))
+        }
+        Some(_) => {}
+    }
+
+    let withdrawn = withdraw_other_active(&mut tx, accepted_id, OffsetDateTime::now_utc()).await?;
+
+    tx.commit().await?;
+    Ok(withdrawn)
+}
+
+/// Why statuses set by [`complete_follow_up`] changed.
+#[cfg(feature = "ssr")]
+const FOLLOW_UP_DONE: &str = "follow-up done";
+
+/// Marks the follow-up of application `id` as done by clearing it. When the
+/// preferences map its status onto a next one, that status is either applied
+/// in the same transaction or returned as a suggestion.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(application_id = %id)))]
+async fn complete_follow_up(id: Uuid) -> Result<FollowUpCompletion, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let preferences = load_preferences(&pool).await?;
+    let mut tx = pool.begin().await?;
+
+    let row: Option<(String, String, Option<String>)> = sqlx::query_as(
+        r#"
+        SELECT c.name, a.status, a.follow_up FROM applications a
+        JOIN companies c ON a.company_id = c.id
+        WHERE a.id = ? AND a.archived_at IS NULL
+        "#,
+    )
+    .bind(id.to_string())
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch application: {e}")))?;
+    let (company, status, follow_up) =
+        row.ok_or_else(|| ServerFnError::new(format!("Application {id} not found")))?;
+    let Some(follow_up) = follow_up else {
+        return Err(ServerFnError::new(format!(
+            "Application {id} has no follow-up"
+        )));
     };
+    let follow_up = Date::parse(&follow_up, DB_DATE)?;
+    let previous: Status = status.parse().map_err(|e: String| ServerFnError::new(e))?;
 
-    view! {
-        <div class="application-card">
-            <span class="card-company">{application.company.name.clone()}</span>
-            <span class="card-industry">{application.company.industry.clone()}</span>
-            <a href=application.company.website.clone() target="_blank" class="card-link">
-                "Visit"
-            </a>
-            <select
-                class=move || format!("status-select {}", status.get().css_class())
-                on:change=on_status_change
-            >
-                <option value="ToDo" selected=move || status.get() == Status::ToDo>"To Do"</option>
-                <option value="Solicitated" selected=move || status.get() == Status::Solicitated>"Applied"</option>
-                <option value="Pending" selected=move || status.get() == Status::Pending>"Pending"</option>
-                <option value="Accepted" selected=move || status.get() == Status::Accepted>"Accepted"</option>
-                <option value="Rejected" selected=move || status.get() == Status::Rejected>"Rejected"</option>
+    set_followup(&mut tx, &[id], None).await?;
+
+    let advance = match preferences.follow_up_advance {
+        FollowUpAdvanceMode::Off => None,
+        FollowUpAdvanceMode::Suggest | FollowUpAdvanceMode::Auto => {
+            preferences.advance_after_follow_up(previous)
+        }
+    };
+    let applied = preferences.follow_up_advance == FollowUpAdvanceMode::Auto && advance.is_some();
+    if let Some(status) = advance.filter(|_| applied) {
+        let now = OffsetDateTime::now_utc();
+        for dates in load_application_dates(&mut tx, &[id]).await? {
+            validate_application_dates(
+                &ApplicationDates {
+                    updated_at: now,
+                    ..dates
+                },
+                now,
+            )?;
+        }
+        sqlx::query("UPDATE applications SET status = ?, updated_at = ? WHERE id = ?")
+            .bind(status.as_str())
+            .bind(date_to_db(now))
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to update status: {e}")))?;
+        record_status_change(&mut tx, id, status, now, Some(FOLLOW_UP_DONE)).await?;
+        if status == Status::Solicitated {
+            await_confirmation(&mut tx, &[id]).await?;
+        }
+    }
+
+    tx.commit().await?;
+    Ok(FollowUpCompletion {
+        id,
+        company,
+        previous,
+        follow_up,
+        advance,
+        applied,
+    })
+}
+
+/// Why statuses set back by [`undo_follow_up_advance`] changed.
+#[cfg(feature = "ssr")]
+const FOLLOW_UP_UNDONE: &str = "follow-up undone";
+
+/// Undoes a [`complete_follow_up`] that moved application `id` on: puts it
+/// back at `previous` and restores the `follow_up` it had.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(application_id = %id)))]
+async fn undo_follow_up_advance(
+    id: Uuid,
+    previous: Status,
+    follow_up: Date,
+) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut tx = pool.begin().await?;
+
+    if set_followup(&mut tx, &[id], Some(follow_up)).await? == 0 {
+        return Err(ServerFnError::new(format!("Application {id} not found")));
+    }
+
+    let now = OffsetDateTime::now_utc();
+    for dates in load_application_dates(&mut tx, &[id]).await? {
+        validate_application_dates(
+            &ApplicationDates {
+                updated_at: now,
+                ..dates
+            },
+            now,
+        )?;
+    }
+    sqlx::query("UPDATE applications SET status = ?, updated_at = ? WHERE id = ?")
+        .bind(previous.as_str())
+        .bind(date_to_db(now))
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to update status: {e}")))?;
+    record_status_change(&mut tx, id, previous, now, Some(FOLLOW_UP_UNDONE)).await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Sets the follow-up date of every application in `ids` to `date`, or clears
+/// it when `date` is `None`.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(count = ids.len())))]
+async fn bulk_set_followup(ids: Vec<Uuid>, date: Option<Date>) -> Result<u64, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut tx = pool.begin().await?;
+
+    let updated = set_followup(&mut tx, &ids, date).await?;
+
+    tx.commit().await?;
+    Ok(updated)
+}
+
+/// Like [`bulk_set_followup`], but for every live application matching
+/// `filter` instead of a list of ids, so selecting all matches does not mean
+/// shipping all their ids.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn bulk_set_followup_matching(
+    filter: ApplicationFilter,
+    date: Option<Date>,
+) -> Result<u64, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut tx = pool.begin().await?;
+
+    let ids = matching_ids(&mut tx, &filter).await?;
+    let updated = set_followup(&mut tx, &ids, date).await?;
+
+    tx.commit().await?;
+    Ok(updated)
+}
+
+/// Moves every application in `ids` to `status`, recording the change in
+/// each one's history. Applications already at `status` are left alone, so
+/// the count returned is of actual changes.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(count = ids.len(), new_status = %status.as_str())))]
+async fn bulk_update_status(ids: Vec<Uuid>, status: Status) -> Result<u64, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut tx = pool.begin().await?;
+
+    let updated = update_statuses(&mut tx, &ids, status).await?;
+
+    tx.commit().await?;
+    Ok(updated)
+}
+
+/// Like [`bulk_update_status`], but for every live application matching
+/// `filter`, as [`bulk_set_followup_matching`] is for follow-ups.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(new_status = %status.as_str())))]
+async fn bulk_update_status_matching(
+    filter: ApplicationFilter,
+    status: Status,
+) -> Result<u64, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut tx = pool.begin().await?;
+
+    let ids = matching_ids(&mut tx, &filter).await?;
+    let updated = update_statuses(&mut tx, &ids, status).await?;
+
+    tx.commit().await?;
+    Ok(updated)
+}
+
+/// How many live applications matching `filter` are at each status, for
+/// confirming a [`bulk_update_status_matching`] without loading them all.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn count_matching_statuses(
+    filter: ApplicationFilter,
+) -> Result<HashMap<Status, usize>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let mut query = QueryBuilder::<Sqlite>::new(
+        r#"
+        SELECT a.status, COUNT(*) FROM applications a
+        JOIN companies c ON a.company_id = c.id
+        WHERE a.archived_at IS NULL
+        "#,
+    );
+    push_filter_conditions(&mut query, &filter);
+    query.push(" GROUP BY a.status");
+
+    let rows: Vec<(String, i64)> = query
+        .build_query_as()
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to count statuses: {e}")))?;
+    Ok(Status::ALL
+        .into_iter()
+        .filter_map(|status| {
+            rows.iter()
+                .find(|(s, _)| s == status.as_str())
+                .map(|(_, count)| (status, *count as usize))
+        })
+        .collect())
+}
+
+/// Moves the applications in `ids` to `status` for [`bulk_update_status`]
+/// and [`bulk_update_status_matching`], returning how many actually changed.
+#[cfg(feature = "ssr")]
+async fn update_statuses(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    ids: &[Uuid],
+    status: Status,
+) -> Result<u64, ServerFnError> {
+    if ids.is_empty() {
+        return Ok(0);
+    }
+
+    let mut query = QueryBuilder::<Sqlite>::new("SELECT id FROM applications WHERE status != ");
+    query.push_bind(status.as_str()).push(" AND id IN (");
+    let mut separated = query.separated(", ");
+    for id in ids {
+        separated.push_bind(id.to_string());
+    }
+    separated.push_unseparated(")");
+    let changing: Vec<String> = query.build_query_scalar().fetch_all(&mut **tx).await?;
+    let changing = changing
+        .iter()
+        .map(|id| Uuid::parse_str(id).map_err(|e| ServerFnError::new(e.to_string())))
+        .collect::<Result<Vec<_>, _>>()?;
+    if changing.is_empty() {
+        return Ok(0);
+    }
+
+    let now = OffsetDateTime::now_utc();
+    for dates in load_application_dates(tx, &changing).await? {
+        validate_application_dates(
+            &ApplicationDates {
+                updated_at: now,
+                ..dates
+            },
+            now,
+        )?;
+    }
+
+    let mut query = QueryBuilder::<Sqlite>::new("UPDATE applications SET status = ");
+    query
+        .push_bind(status.as_str())
+        .push(", updated_at = ")
+        .push_bind(date_to_db(now))
+        .push(" WHERE id IN (");
+    let mut separated = query.separated(", ");
+    for id in &changing {
+        separated.push_bind(id.to_string());
+    }
+    separated.push_unseparated(")");
+    query
+        .build()
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to update statuses: {e}")))?;
+
+    for id in &changing {
+        record_status_change(tx, *id, status, now, None).await?;
+    }
+    if status == Status::Solicitated {
+        await_confirmation(tx, &changing).await?;
+    }
+
+    Ok(changing.len() as u64)
+}
+
+/// Resolves `filter` to the ids of the live applications it matches.
+#[cfg(feature = "ssr")]
+async fn matching_ids(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    filter: &ApplicationFilter,
+) -> Result<Vec<Uuid>, ServerFnError> {
+    let mut query = QueryBuilder::<Sqlite>::new(
+        r#"
+        SELECT a.id FROM applications a
+        JOIN companies c ON a.company_id = c.id
+        WHERE a.archived_at IS NULL
+        "#,
+    );
+    push_filter_conditions(&mut query, filter);
+
+    let ids: Vec<String> = query.build_query_scalar().fetch_all(&mut **tx).await?;
+    ids.iter()
+        .map(|id| Uuid::parse_str(id).map_err(|e| ServerFnError::new(e.to_string())))
+        .collect()
+}
+
+/// Sets the follow-up of every application in `ids`, first checking it
+/// against each one's stored dates.
+#[cfg(feature = "ssr")]
+async fn set_followup(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    ids: &[Uuid],
+    date: Option<Date>,
+) -> Result<u64, ServerFnError> {
+    if ids.is_empty() {
+        return Ok(0);
+    }
+
+    let now = OffsetDateTime::now_utc();
+    for dates in load_application_dates(tx, ids).await? {
+        validate_application_dates(
+            &ApplicationDates {
+                updated_at: now,
+                follow_up: date,
+                ..dates
+            },
+            now,
+        )?;
+    }
+
+    let mut query = QueryBuilder::<Sqlite>::new("UPDATE applications SET follow_up = ");
+    query
+        .push_bind(date.map(|d| d.format(DB_DATE)).transpose()?)
+        .push(", updated_at = ")
+        .push_bind(date_to_db(now))
+        .push(" WHERE id IN (");
+    let mut separated = query.separated(", ");
+    for id in ids {
+        separated.push_bind(id.to_string());
+    }
+    separated.push_unseparated(")");
+
+    Ok(query.build().execute(&mut **tx).await?.rows_affected())
+}
+
+/// Replaces the notes of application `id`, recording the edit for the
+/// activity timeline.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, skip(notes)))]
+async fn set_notes(id: Uuid, notes: String) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut tx = pool.begin().await?;
+    let now = date_to_db(OffsetDateTime::now_utc());
+
+    let updated = sqlx::query("UPDATE applications SET notes = ?, updated_at = ? WHERE id = ?")
+        .bind(notes.trim())
+        .bind(&now)
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to save notes: {e}")))?
+        .rows_affected();
+    if updated == 0 {
+        return Err(ServerFnError::new(format!("Application {id} not found")));
+    }
+    sqlx::query("INSERT INTO note_edits (application_id, edited_at) VALUES (?, ?)")
+        .bind(id.to_string())
+        .bind(&now)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to record note edit: {e}")))?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Marks whether the company confirmed receiving application `id`.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn set_confirmation_received(id: Uuid, received: bool) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let updated = sqlx::query(
+        "UPDATE applications SET confirmation_received = ?, updated_at = ? WHERE id = ?",
+    )
+    .bind(received)
+    .bind(date_to_db(OffsetDateTime::now_utc()))
+    .bind(id.to_string())
+    .execute(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to save confirmation: {e}")))?
+    .rows_affected();
+    if updated == 0 {
+        return Err(ServerFnError::new(format!("Application {id} not found")));
+    }
+
+    Ok(())
+}
+
+/// Starts waiting for a confirmation of receipt for the applications in
+/// `ids`, now that they were sent.
+#[cfg(feature = "ssr")]
+async fn await_confirmation(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    ids: &[Uuid],
+) -> Result<(), ServerFnError> {
+    if ids.is_empty() {
+        return Ok(());
+    }
+
+    let mut query = QueryBuilder::<Sqlite>::new(
+        "UPDATE applications SET confirmation_received = 0 WHERE id IN (",
+    );
+    let mut separated = query.separated(", ");
+    for id in ids {
+        separated.push_bind(id.to_string());
+    }
+    separated.push_unseparated(")");
+    query
+        .build()
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to update confirmation: {e}")))?;
+
+    Ok(())
+}
+
+/// Lists the live applications sent over [`VERIFY_SUBMISSION_DAYS`] days ago
+/// that are still waiting for a confirmation of receipt, oldest first, as
+/// they may never have gone through.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_unconfirmed_submissions() -> Result<Vec<AllApplicationsResponse>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let cutoff = OffsetDateTime::now_utc() - time::Duration::days(VERIFY_SUBMISSION_DAYS);
+    let rows: Vec<ApplicationRow> = sqlx::query_as(&format!(
+        r#"
+        {SELECT_APPLICATIONS}
+        WHERE a.archived_at IS NULL AND a.status = ?1 AND a.confirmation_received = 0
+          AND (
+            SELECT MAX(h.changed_at) FROM status_history h
+            WHERE h.application_id = a.id AND h.status = ?1
+          ) < ?2
+        ORDER BY a.date
+        "#
+    ))
+    .bind(Status::Solicitated.as_str())
+    .bind(date_to_db(cutoff))
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch applications: {e}")))?;
+
+    rows.into_iter().map(TryFrom::try_from).collect()
+}
+
+/// Sets or clears the date the posting of application `id` closes.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn set_deadline(id: Uuid, deadline: Option<Date>) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut tx = pool.begin().await?;
+
+    let now = OffsetDateTime::now_utc();
+    let Some(dates) = load_application_dates(&mut tx, &[id]).await?.pop() else {
+        return Err(ServerFnError::new(format!("Application {id} not found")));
+    };
+    validate_application_dates(
+        &ApplicationDates {
+            updated_at: now,
+            ..dates
+        },
+        now,
+    )?;
+
+    sqlx::query("UPDATE applications SET deadline = ?, updated_at = ? WHERE id = ?")
+        .bind(deadline.map(|d| d.format(DB_DATE)).transpose()?)
+        .bind(date_to_db(now))
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to set deadline: {e}")))?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Lists the active to-do applications whose posting closes within the next
+/// [`DEADLINE_SOON_DAYS`] days, soonest first.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_upcoming_deadlines() -> Result<Vec<AllApplicationsResponse>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let today = OffsetDateTime::now_utc().date();
+    let until = today + time::Duration::days(DEADLINE_SOON_DAYS);
+
+    let rows: Vec<ApplicationRow> = sqlx::query_as(&format!(
+        r#"
+        {SELECT_APPLICATIONS}
+        WHERE a.archived_at IS NULL AND a.status = ? AND a.deadline BETWEEN ? AND ?
+        ORDER BY a.deadline
+        "#
+    ))
+    .bind(Status::ToDo.as_str())
+    .bind(today.format(DB_DATE)?)
+    .bind(until.format(DB_DATE)?)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch deadlines: {e}")))?;
+
+    rows.into_iter().map(TryFrom::try_from).collect()
+}
+
+/// Gathers what needs doing today, most urgent first: to-do applications
+/// whose posting closes today, follow-ups due today or earlier, and the
+/// remaining to-do applications. Each application shows up only once, and
+/// finished ones not at all.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_today() -> Result<TodayResponse, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let today = OffsetDateTime::now_utc().date();
+    let mut query = QueryBuilder::<Sqlite>::new(format!(
+        "{SELECT_APPLICATIONS} WHERE a.archived_at IS NULL AND (a.status = "
+    ));
+    query
+        .push_bind(Status::ToDo.as_str())
+        .push(" OR a.follow_up <= ")
+        .push_bind(today.format(DB_DATE)?)
+        // A follow-up left on a finished application is nothing to do today
+        .push(") AND a.status NOT IN (");
+    let mut separated = query.separated(", ");
+    for status in Status::ALL.iter().filter(|status| status.is_finished()) {
+        separated.push_bind(status.as_str());
+    }
+    separated.push_unseparated(") ORDER BY a.date");
+    let rows: Vec<ApplicationRow> = query
+        .build_query_as()
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to fetch applications: {e}")))?;
+
+    let mut buckets = TodayResponse::default();
+    for row in rows {
+        let application = AllApplicationsResponse::try_from(row)?;
+        if application.status == Status::ToDo && application.deadline == Some(today) {
+            buckets.deadlines.push(application);
+        } else if application.follow_up.is_some_and(|date| date <= today) {
+            buckets.follow_ups.push(application);
+        } else {
+            buckets.to_do.push(application);
+        }
+    }
+
+    Ok(buckets)
+}
+
+/// Picks the single application most worth acting on now, with the reason
+/// why, or `None` when nothing is actionable.
+///
+/// Overdue follow-ups come first, then to-do applications about to close,
+/// then pending applications gone quiet, then the oldest to-do ones.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_next_action() -> Result<Option<NextAction>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let applications = fetch_applications(&pool, &ApplicationFilter::default()).await?;
+    let now = OffsetDateTime::now_utc();
+
+    Ok(applications
+        .into_iter()
+        .filter_map(|application| {
+            let (score, reason) = next_action_score(&application, now)?;
+            Some((
+                score,
+                NextAction {
+                    application,
+                    reason,
+                },
+            ))
+        })
+        .max_by_key(|(score, _)| *score)
+        .map(|(_, action)| action))
+}
+
+/// Scores how urgently `application` needs attention, with the reason for
+/// it, or `None` when there is nothing to do for it, as for every finished
+/// application.
+#[cfg(feature = "ssr")]
+fn next_action_score(
+    application: &AllApplicationsResponse,
+    now: OffsetDateTime,
+) -> Option<(i64, String)> {
+    // A follow-up left on a finished application is nothing to act on
+    if application.status.is_finished() {
+        return None;
+    }
+
+    let today = now.date();
+    let days = |n: i64| n.clamp(0, MAX_DAYS_SCORED) * SCORE_PER_DAY;
+
+    if let Some(follow_up) = application.follow_up.filter(|date| *date <= today) {
+        let overdue = (today - follow_up).whole_days();
+        let reason = match overdue {
+            0 => "Follow-up due today".to_string(),
+            1 => "Follow-up overdue by 1 day".to_string(),
+            n => format!("Follow-up overdue by {n} days"),
+        };
+        return Some((FOLLOW_UP_SCORE + days(overdue), reason));
+    }
+
+    match application.status {
+        Status::ToDo => {
+            let closes_soon = application
+                .deadline
+                .map(|deadline| (deadline - today).whole_days())
+                .filter(|left| (0..=DEADLINE_SOON_DAYS).contains(left));
+            if let Some(left) = closes_soon {
+                let reason = match left {
+                    0 => "Posting closes today".to_string(),
+                    1 => "Posting closes tomorrow".to_string(),
+                    n => format!("Posting closes in {n} days"),
+                };
+                return Some((DEADLINE_SCORE + days(DEADLINE_SOON_DAYS - left), reason));
+            }
+            let age = (today - application.date.date()).whole_days();
+            Some((TO_DO_SCORE + days(age), "Not applied yet".to_string()))
+        }
+        Status::Pending => {
+            let quiet = (now - application.updated_at).whole_days();
+            (quiet >= STALE_PENDING_DAYS).then(|| {
+                (
+                    STALE_PENDING_SCORE + days(quiet - STALE_PENDING_DAYS),
+                    format!("No news for {quiet} days"),
+                )
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Loads the stored timestamps of the applications in `ids`.
+#[cfg(feature = "ssr")]
+async fn load_application_dates(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    ids: &[Uuid],
+) -> Result<Vec<ApplicationDates>, ServerFnError> {
+    let mut query = QueryBuilder::<Sqlite>::new(
+        "SELECT date, updated_at, follow_up FROM applications WHERE id IN (",
+    );
+    let mut separated = query.separated(", ");
+    for id in ids {
+        separated.push_bind(id.to_string());
+    }
+    separated.push_unseparated(")");
+
+    let rows: Vec<(String, String, Option<String>)> =
+        query.build_query_as().fetch_all(&mut **tx).await?;
+
+    rows.into_iter()
+        .map(|(date, updated_at, follow_up)| {
+            Ok(ApplicationDates {
+                applied_at: date_from_db(&date)?,
+                updated_at: date_from_db(&updated_at)?,
+                follow_up: follow_up.map(|d| Date::parse(&d, DB_DATE)).transpose()?,
+            })
+        })
+        .collect()
+}
+
+/// Archives every live application with one of `statuses` dated before
+/// `before`, returning them so they can be kept as a JSON export.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(before = %before)))]
+async fn archive_and_export(
+    statuses: Vec<Status>,
+    before: Date,
+) -> Result<ArchiveExport, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    if statuses.is_empty() {
+        return Err(ServerFnError::new("Select at least one status to archive"));
+    }
+
+    let mut tx = pool.begin().await?;
+
+    let mut query = QueryBuilder::<Sqlite>::new(format!(
+        "{SELECT_APPLICATIONS} WHERE a.archived_at IS NULL AND substr(a.date, 1, 10) < "
+    ));
+    query
+        .push_bind(before.format(DB_DATE)?)
+        .push(" AND a.status IN (");
+    let mut separated = query.separated(", ");
+    for status in &statuses {
+        separated.push_bind(status.as_str());
+    }
+    separated.push_unseparated(") ORDER BY a.date");
+
+    let rows: Vec<ApplicationRow> = query
+        .build_query_as()
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to fetch applications: {e}")))?;
+    let mut applications = rows
+        .into_iter()
+        .map(AllApplicationsResponse::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if !applications.is_empty() {
+        // The export is all that is kept of them, so it takes their notes too
+        let mut query =
+            QueryBuilder::<Sqlite>::new("SELECT id, notes FROM applications WHERE id IN (");
+        let mut separated = query.separated(", ");
+        for application in &applications {
+            separated.push_bind(application.id.to_string());
+        }
+        separated.push_unseparated(")");
+        let mut notes: HashMap<String, String> = query
+            .build_query_as::<(String, String)>()
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to fetch notes: {e}")))?
+            .into_iter()
+            .collect();
+        for application in &mut applications {
+            application.notes = notes.remove(&application.id.to_string());
+        }
+
+        let now = date_to_db(OffsetDateTime::now_utc());
+        let mut query = QueryBuilder::<Sqlite>::new("UPDATE applications SET archived_at = ");
+        query
+            .push_bind(now.clone())
+            .push(", updated_at = ")
+            .push_bind(now)
+            .push(" WHERE id IN (");
+        let mut separated = query.separated(", ");
+        for application in &applications {
+            separated.push_bind(application.id.to_string());
+        }
+        separated.push_unseparated(")");
+        query.build().execute(&mut *tx).await?;
+    }
+
+    tx.commit().await?;
+    Ok(ArchiveExport {
+        archived: applications.len(),
+        applications,
+    })
+}
+
+/// Restores applications from the JSON of an archive export, resolving
+/// applications that already exist by id according to `conflict_mode`.
+///
+/// Imported applications are live again, even if they were archived when
+/// exported. Companies are matched by id as well, and only overwritten in
+/// [`ConflictMode::Overwrite`]. Everything happens in one transaction, so an
+/// invalid record leaves the database untouched.
+///
+/// New applications, their companies and their history are written in
+/// multi-row INSERTs of [`IMPORT_BATCH_SIZE`] rows through an
+/// [`ImportBatch`]: four statements per batch, plus one lookup per industry
+/// not seen before. Only overwrites still go one row at a time.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, skip(json)))]
+pub(crate) async fn import_applications(
+    json: String,
+    conflict_mode: ConflictMode,
+) -> Result<Vec<ImportOutcome>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let records: Vec<AllApplicationsResponse> = serde_json::from_str(&json)
+        .map_err(|e| ServerFnError::new(format!("Not a valid export: {e}")))?;
+
+    let now = OffsetDateTime::now_utc();
+    for record in &records {
+        validate_application_dates(
+            &ApplicationDates {
+                applied_at: record.date,
+                updated_at: record.updated_at,
+                follow_up: record.follow_up,
+            },
+            now,
+        )?;
+    }
+
+    let mut tx = pool.begin().await?;
+    let ids: Vec<Uuid> = records.iter().map(|record| record.id).collect();
+    // Ids are added as records are imported, so a record repeated in the
+    // file conflicts with its first copy just as with a stored application
+    let mut known = existing_application_ids(&mut tx, &ids).await?;
+    let mut batch = ImportBatch::default();
+    let mut outcomes = Vec::with_capacity(records.len());
+
+    for record in &records {
+        let result = match (known.contains(&record.id), conflict_mode) {
+            (false, _) => {
+                batch.push(record.id, record);
+                ImportResult::Created
+            }
+            (true, ConflictMode::Skip) => ImportResult::Skipped,
+            (true, ConflictMode::Overwrite) => {
+                // The application may be one still waiting in the batch
+                batch.flush(&mut tx).await?;
+                import_company(&mut tx, &record.company, true).await?;
+                overwrite_application(&mut tx, record, now).await?;
+                ImportResult::Overwritten
+            }
+            (true, ConflictMode::CreateNew) => {
+                let new_id = Uuid::new_v4();
+                batch.push(new_id, record);
+                ImportResult::CreatedAs(new_id)
+            }
+        };
+        known.insert(record.id);
+        if batch.applications.len() >= IMPORT_BATCH_SIZE {
+            batch.flush(&mut tx).await?;
+        }
+
+        outcomes.push(ImportOutcome {
+            id: record.id,
+            company: record.company.name.clone(),
+            result,
+        });
+    }
+    batch.flush(&mut tx).await?;
+
+    tx.commit().await?;
+    Ok(outcomes)
+}
+
+/// Exports every application, archived ones included, with its notes,
+/// history and reminders, but with anything naming a company or a person
+/// replaced by a placeholder. Ids, statuses, dates and industries are kept,
+/// so the export still reproduces a problem, and it imports like an archive
+/// export. See [`Placeholders`] for how repeated exports stay consistent.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn export_anonymized() -> Result<Vec<AnonymizedApplication>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        "INSERT INTO anonymization_salt (id, salt) VALUES (1, ?) ON CONFLICT(id) DO NOTHING",
+    )
+    .bind(Uuid::new_v4().simple().to_string())
+    .execute(&mut *tx)
+    .await?;
+    let salt: String = sqlx::query_scalar("SELECT salt FROM anonymization_salt WHERE id = 1")
+        .fetch_one(&mut *tx)
+        .await?;
+    let placeholders = Placeholders { salt };
+
+    let rows: Vec<ApplicationRow> =
+        sqlx::query_as(&format!("{SELECT_APPLICATIONS} ORDER BY a.date, a.id"))
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to fetch applications: {e}")))?;
+    let notes: Vec<(String, String)> = sqlx::query_as("SELECT id, notes FROM applications")
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to fetch notes: {e}")))?;
+    let history: Vec<ApplicationHistoryRow> = sqlx::query_as(
+        "SELECT application_id, status, changed_at, reason FROM status_history ORDER BY id",
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch status history: {e}")))?;
+    let reminders: Vec<ReminderRow> = sqlx::query_as(
+        "SELECT id, application_id, remind_at, text, done FROM reminders ORDER BY remind_at, id",
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch reminders: {e}")))?;
+    tx.commit().await?;
+
+    let mut notes: HashMap<String, String> = notes.into_iter().collect();
+    let mut history_by_application: HashMap<Uuid, Vec<StatusChange>> = HashMap::new();
+    for row in history {
+        let id =
+            Uuid::parse_str(&row.application_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+        history_by_application
+            .entry(id)
+            .or_default()
+            .push(row.change.try_into()?);
+    }
+    let mut reminders_by_application: HashMap<Uuid, Vec<Reminder>> = HashMap::new();
+    for row in reminders {
+        let mut reminder = Reminder::try_from(row)?;
+        reminder.text = placeholders.text("Reminder", &reminder.text);
+        reminders_by_application
+            .entry(reminder.application_id)
+            .or_default()
+            .push(reminder);
+    }
+
+    rows.into_iter()
+        .map(|row| {
+            let mut application = AllApplicationsResponse::try_from(row)?;
+            application.company = placeholders.company(application.company);
+            let id = application.id;
+            Ok(AnonymizedApplication {
+                notes: placeholders
+                    .text("Notes", &notes.remove(&id.to_string()).unwrap_or_default()),
+                history: history_by_application.remove(&id).unwrap_or_default(),
+                reminders: reminders_by_application.remove(&id).unwrap_or_default(),
+                application,
+            })
+        })
+        .collect()
+}
+
+/// Stands in for private text in [`export_anonymized`]. A placeholder is
+/// named after the kind of value and a SHA-256 of it keyed with this
+/// database's secret salt: the same value always gets the same placeholder,
+/// yet a guessed name can't be checked against it outside this database.
+/// Blank values stay blank, as whether something is filled in matters.
+#[cfg(feature = "ssr")]
+struct Placeholders {
+    salt: String,
+}
+
+#[cfg(feature = "ssr")]
+impl Placeholders {
+    fn hash(&self, kind: &str, value: &str) -> String {
+        use sha2::{Digest, Sha256};
+
+        let digest = Sha256::new()
+            .chain_update(&self.salt)
+            .chain_update([0u8])
+            .chain_update(kind)
+            .chain_update([0u8])
+            .chain_update(value)
+            .finalize();
+        digest[..4].iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn text(&self, kind: &str, value: &str) -> String {
+        if value.trim().is_empty() {
+            return String::new();
+        }
+        format!("{kind} {}", self.hash(kind, value.trim()))
+    }
+
+    /// Websites that [`website_key`] takes for the same site get the same
+    /// placeholder, so duplicate detection behaves as on the real data.
+    fn website(&self, website: &str) -> String {
+        if website.trim().is_empty() {
+            return String::new();
+        }
+        format!(
+            "https://{}.example",
+            self.hash("Website", &website_key(website))
+        )
+    }
+
+    fn company(&self, company: Company) -> Company {
+        Company {
+            name: self.text("Company", &company.name),
+            website: self.website(&company.website),
+            ceo: self.text("Contact", &company.ceo),
+            ..company
+        }
+    }
+}
+
+/// Exports how this tracker is set up, without any application or company:
+/// the preferences, the saved filters and the industries with their aliases.
+/// Kept apart from [`archive_and_export`] so a template never carries
+/// personal data, which is also why filters lose their search text.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn export_pipeline_template() -> Result<PipelineTemplate, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let mut preferences = load_preferences(&pool).await?;
+    preferences.default_view = preferences.default_view.without_search();
+
+    let presets: Vec<FilterPresetRow> = sqlx::query_as(
+        "SELECT id, name, status, search, search_notes, sort, date_from, date_to FROM filter_presets ORDER BY name",
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch filter presets: {e}")))?;
+    let filter_presets = presets
+        .into_iter()
+        .map(|row| {
+            let preset = FilterPreset::try_from(row)?;
+            Ok(PresetTemplate {
+                name: preset.name,
+                filter: preset.filter.without_search(),
+            })
+        })
+        .collect::<Result<_, ServerFnError>>()?;
+
+    let industries: Vec<(String, String)> =
+        sqlx::query_as("SELECT id, name FROM industries ORDER BY name")
+            .fetch_all(&pool)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to fetch industries: {e}")))?;
+    let aliases: Vec<(String, String)> =
+        sqlx::query_as("SELECT alias, industry_id FROM industry_aliases ORDER BY alias")
+            .fetch_all(&pool)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to fetch industry aliases: {e}")))?;
+    let industries = industries
+        .into_iter()
+        .map(|(id, name)| IndustryTemplate {
+            aliases: aliases
+                .iter()
+                .filter(|(_, industry_id)| *industry_id == id)
+                .map(|(alias, _)| alias.clone())
+                .collect(),
+            id,
+            name,
+        })
+        .collect();
+
+    Ok(PipelineTemplate {
+        version: PIPELINE_TEMPLATE_VERSION,
+        preferences,
+        filter_presets,
+        industries,
+    })
+}
+
+/// Sets this tracker up from the JSON of [`export_pipeline_template`]. The
+/// preferences are replaced; filters and industries are added, leaving
+/// any with the same name as they are.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, skip(json)))]
+async fn import_pipeline_template(json: String) -> Result<TemplateImport, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let template: PipelineTemplate = serde_json::from_str(&json)
+        .map_err(|e| ServerFnError::new(format!("Not a valid template: {e}")))?;
+    if template.version != PIPELINE_TEMPLATE_VERSION {
+        return Err(ServerFnError::new(format!(
+            "Unsupported template version {}",
+            template.version
+        )));
+    }
+
+    let mut tx = pool.begin().await?;
+    let mut imported = TemplateImport::default();
+
+    sqlx::query(
+        "INSERT INTO preferences (id, data) VALUES (1, ?) ON CONFLICT(id) DO UPDATE SET data = excluded.data",
+    )
+    .bind(serde_json::to_string(&template.preferences)?)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to save preferences: {e}")))?;
+
+    for preset in template.filter_presets {
+        let filter = preset.filter.without_search();
+        imported.filter_presets += sqlx::query(
+            r#"
+            INSERT INTO filter_presets (id, name, status, search, search_notes, sort, date_from, date_to)
+            VALUES (?, ?, ?, NULL, ?, ?, ?, ?)
+            ON CONFLICT(name) DO NOTHING
+            "#,
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(preset.name.trim())
+        .bind(filter.status.map(|s| s.as_str()))
+        .bind(filter.search_notes)
+        .bind(filter.sort.as_str())
+        .bind(filter.from)
+        .bind(filter.to)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to import filter {}: {e}", preset.name)))?
+        .rows_affected();
+    }
+
+    for industry in template.industries {
+        imported.industries +=
+            sqlx::query("INSERT INTO industries (id, name) VALUES (?, ?) ON CONFLICT DO NOTHING")
+                .bind(&industry.id)
+                .bind(industry.name.trim())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    ServerFnError::new(format!("Failed to import industry {}: {e}", industry.name))
+                })?
+                .rows_affected();
+        // Aliases follow the industry by name, as a clash may have kept
+        // the existing one under another id
+        for alias in &industry.aliases {
+            imported.aliases += sqlx::query(
+                r#"
+                INSERT INTO industry_aliases (alias, industry_id)
+                SELECT ?, id FROM industries WHERE name = ?
+                ON CONFLICT DO NOTHING
+                "#,
+            )
+            .bind(industry_key(alias))
+            .bind(industry.name.trim())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to import alias {alias}: {e}")))?
+            .rows_affected();
+        }
+    }
+
+    tx.commit().await?;
+    Ok(imported)
+}
+
+/// The format of [`PipelineTemplate`], bumped when it changes incompatibly.
+#[cfg(feature = "ssr")]
+const PIPELINE_TEMPLATE_VERSION: u32 = 1;
+
+/// Applications written per multi-row INSERT by [`import_applications`]. At
+/// eight parameters a row this stays well below SQLite's limit of 32766
+/// bound parameters per statement.
+#[cfg(feature = "ssr")]
+const IMPORT_BATCH_SIZE: usize = 500;
+
+/// Which of `ids` are already taken by an application, archived or not.
+#[cfg(feature = "ssr")]
+async fn existing_application_ids(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    ids: &[Uuid],
+) -> Result<HashSet<Uuid>, ServerFnError> {
+    let mut existing = HashSet::new();
+    for chunk in ids.chunks(IMPORT_BATCH_SIZE) {
+        let mut query = QueryBuilder::<Sqlite>::new("SELECT id FROM applications WHERE id IN (");
+        let mut separated = query.separated(", ");
+        for id in chunk {
+            separated.push_bind(id.to_string());
+        }
+        separated.push_unseparated(")");
+
+        let found: Vec<String> = query
+            .build_query_scalar()
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to fetch applications: {e}")))?;
+        for id in found {
+            existing.insert(parse_application_id(&id)?);
+        }
+    }
+
+    Ok(existing)
+}
+
+/// New applications waiting to be inserted by [`import_applications`],
+/// along with their companies and the history seeding their status.
+#[cfg(feature = "ssr")]
+#[derive(Default)]
+struct ImportBatch<'a> {
+    applications: Vec<(Uuid, &'a AllApplicationsResponse)>,
+    /// Industry ids by name, kept across flushes as most imports share a
+    /// handful of industries.
+    industries: HashMap<String, Option<String>>,
+}
+
+#[cfg(feature = "ssr")]
+impl<'a> ImportBatch<'a> {
+    fn push(&mut self, id: Uuid, record: &'a AllApplicationsResponse) {
+        self.applications.push((id, record));
+    }
+
+    /// Writes the waiting applications in three statements: their
+    /// companies, the applications themselves and their history.
+    async fn flush(&mut self, tx: &mut sqlx::Transaction<'_, Sqlite>) -> Result<(), ServerFnError> {
+        let applications = std::mem::take(&mut self.applications);
+        if applications.is_empty() {
+            return Ok(());
+        }
+
+        // The first copy of a company wins, as it would row by row
+        let mut seen = HashSet::new();
+        let mut companies = Vec::new();
+        for (_, record) in &applications {
+            let company = &record.company;
+            if !seen.insert(company.id) {
+                continue;
+            }
+            let industry_id = match self.industries.get(&company.industry) {
+                Some(id) => id.clone(),
+                None => {
+                    let id = resolve_industry(tx, &company.industry).await?;
+                    self.industries.insert(company.industry.clone(), id.clone());
+                    id
+                }
+            };
+            companies.push((company, normalize_website(&company.website)?, industry_id));
+        }
+
+        let mut query = QueryBuilder::<Sqlite>::new(
+            "INSERT INTO companies (id, name, website, ceo, industry, industry_id) ",
+        );
+        query.push_values(companies, |mut row, (company, website, industry_id)| {
+            row.push_bind(company.id.to_string())
+                .push_bind(&company.name)
+                .push_bind(website)
+                .push_bind(&company.ceo)
+                .push_bind(&company.industry)
+                .push_bind(industry_id);
+        });
+        query.push(" ON CONFLICT (id) DO NOTHING");
+        query
+            .build()
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to import companies: {e}")))?;
+
+        let mut rows = Vec::with_capacity(applications.len());
+        for (id, record) in &applications {
+            rows.push((
+                id,
+                record,
+                record.follow_up.map(|d| d.format(DB_DATE)).transpose()?,
+                record.deadline.map(|d| d.format(DB_DATE)).transpose()?,
+            ));
+        }
+        let mut query = QueryBuilder::<Sqlite>::new(
+            "INSERT INTO applications (id, company_id, status, date, updated_at, follow_up, deadline, notes) ",
+        );
+        query.push_values(rows, |mut row, (id, record, follow_up, deadline)| {
+            row.push_bind(id.to_string())
+                .push_bind(record.company.id.to_string())
+                .push_bind(record.status.as_str())
+                .push_bind(date_to_db(record.date))
+                .push_bind(date_to_db(record.updated_at))
+                .push_bind(follow_up)
+                .push_bind(deadline)
+                .push_bind(record.notes.as_deref().unwrap_or_default());
+        });
+        query
+            .build()
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to import applications: {e}")))?;
+
+        let mut query = QueryBuilder::<Sqlite>::new(
+            "INSERT INTO status_history (application_id, status, changed_at, reason) ",
+        );
+        query.push_values(&applications, |mut row, (id, record)| {
+            row.push_bind(id.to_string())
+                .push_bind(record.status.as_str())
+                .push_bind(date_to_db(record.updated_at))
+                .push_bind(IMPORTED);
+        });
+        query
+            .build()
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to record status history: {e}")))?;
+
+        Ok(())
+    }
+}
+
+/// Adds the company of an imported application unless one with its id
+/// exists, in which case it is only updated when `overwrite` is set.
+#[cfg(feature = "ssr")]
+async fn import_company(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    company: &Company,
+    overwrite: bool,
+) -> Result<(), ServerFnError> {
+    let industry_id = resolve_industry(tx, &company.industry).await?;
+    let website = normalize_website(&company.website)?;
+
+    let on_conflict = if overwrite {
+        "ON CONFLICT (id) DO UPDATE SET name = excluded.name, website = excluded.website, \
+         ceo = excluded.ceo, industry = excluded.industry, industry_id = excluded.industry_id"
+    } else {
+        "ON CONFLICT (id) DO NOTHING"
+    };
+    sqlx::query(&format!(
+        "INSERT INTO companies (id, name, website, ceo, industry, industry_id) \
+         VALUES (?, ?, ?, ?, ?, ?) {on_conflict}"
+    ))
+    .bind(company.id.to_string())
+    .bind(&company.name)
+    .bind(website)
+    .bind(&company.ceo)
+    .bind(&company.industry)
+    .bind(industry_id)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to import company {}: {e}", company.name)))?;
+
+    Ok(())
+}
+
+/// Replaces an existing application with its imported version, recording
+/// the status in the history when the import changes it.
+#[cfg(feature = "ssr")]
+async fn overwrite_application(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    record: &AllApplicationsResponse,
+    now: OffsetDateTime,
+) -> Result<(), ServerFnError> {
+    let previous: String = sqlx::query_scalar("SELECT status FROM applications WHERE id = ?")
+        .bind(record.id.to_string())
+        .fetch_one(&mut **tx)
+        .await?;
+
+    // Exports from before notes were included leave the stored ones alone
+    sqlx::query(
+        "UPDATE applications SET company_id = ?, status = ?, date = ?, updated_at = ?, follow_up = ?, deadline = ?, notes = COALESCE(?, notes), archived_at = NULL WHERE id = ?",
+    )
+    .bind(record.company.id.to_string())
+    .bind(record.status.as_str())
+    .bind(date_to_db(record.date))
+    .bind(date_to_db(now))
+    .bind(record.follow_up.map(|d| d.format(DB_DATE)).transpose()?)
+    .bind(record.deadline.map(|d| d.format(DB_DATE)).transpose()?)
+    .bind(record.notes.as_deref())
+    .bind(record.id.to_string())
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to import application {}: {e}", record.id)))?;
+
+    if previous != record.status.as_str() {
+        record_status_change(tx, record.id, record.status, now, Some(IMPORTED)).await?;
+    }
+
+    Ok(())
+}
+
+/// The history reason of statuses set by [`import_applications`].
+#[cfg(feature = "ssr")]
+const IMPORTED: &str = "imported";
+
+#[cfg(feature = "ssr")]
+async fn record_status_change(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    application_id: Uuid,
+    status: Status,
+    changed_at: OffsetDateTime,
+    reason: Option<&str>,
+) -> Result<(), ServerFnError> {
+    sqlx::query(
+        "INSERT INTO status_history (application_id, status, changed_at, reason) VALUES (?, ?, ?, ?)",
+    )
+    .bind(application_id.to_string())
+    .bind(status.as_str())
+    .bind(date_to_db(changed_at))
+    .bind(reason)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, skip(req), fields(company = %req.company.name)))]
+async fn create_application(req: CreateApplicationRequest) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let company = Company::new(
+        req.company.name,
+        normalize_website(&req.company.website)?,
+        req.company.ceo,
+        req.company.industry,
+    );
+    let application = Application::new(&company, req.status, req.deadline);
+
+    validate_application_dates(
+        &ApplicationDates {
+            applied_at: application.date,
+            updated_at: application.date,
+            follow_up: None,
+        },
+        OffsetDateTime::now_utc(),
+    )?;
+
+    insert_application(&pool, &application).await
+}
+
+#[cfg(feature = "ssr")]
+async fn insert_application(
+    pool: &SqlitePool,
+    application: &Application,
+) -> Result<(), ServerFnError> {
+    let mut tx = pool.begin().await?;
+
+    let industry_id = resolve_industry(&mut tx, &application.company.industry).await?;
+
+    sqlx::query(
+        "INSERT INTO companies (id, name, website, ceo, industry, industry_id) VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(application.company.id.to_string())
+    .bind(&application.company.name)
+    .bind(&application.company.website)
+    .bind(&application.company.ceo)
+    .bind(&application.company.industry)
+    .bind(industry_id)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO applications (id, company_id, status, date, updated_at, deadline, confirmation_received) VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(application.id.to_string())
+    .bind(application.company.id.to_string())
+    .bind(application.status.as_str())
+    .bind(date_to_db(application.date))
+    .bind(date_to_db(application.date))
+    .bind(application.deadline.map(|d| d.format(DB_DATE)).transpose()?)
+    .bind((application.status == Status::Solicitated).then_some(false))
+    .execute(&mut *tx)
+    .await?;
+
+    record_status_change(
+        &mut tx,
+        application.id,
+        application.status,
+        application.date,
+        None,
+    )
+    .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Lists companies without any live application, because all of theirs have
+/// been archived, together with how many archived applications they hold.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_inactive_companies() -> Result<Vec<CompanyUsage>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let rows: Vec<CompanyUsageRow> = sqlx::query_as(
+        r#"
+        SELECT c.id, c.name, c.website, c.ceo, c.industry,
+               COUNT(a.id) as application_count
+        FROM companies c
+        LEFT JOIN applications a ON a.company_id = c.id
+        GROUP BY c.id
+        HAVING SUM(a.id IS NOT NULL AND a.archived_at IS NULL) = 0
+        ORDER BY c.name
+        "#,
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch companies: {e}")))?;
+
+    rows.into_iter().map(TryFrom::try_from).collect()
+}
+
+/// Removes an inactive company together with its archived applications and
+/// their history. Companies that still have live applications are refused.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn remove_inactive_company(id: Uuid) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut tx = pool.begin().await?;
+
+    let live: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM applications WHERE company_id = ? AND archived_at IS NULL",
+    )
+    .bind(id.to_string())
+    .fetch_one(&mut *tx)
+    .await?;
+    if live > 0 {
+        return Err(ServerFnError::new(format!(
+            "Company {id} still has {live} active application(s)"
+        )));
+    }
+
+    // The trigger on applications removes the company along with its last
+    // application; the second delete covers companies that had none
+    sqlx::query("DELETE FROM applications WHERE company_id = ?")
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("DELETE FROM companies WHERE id = ?")
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Lists every company, for picking one to move an application to.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_companies() -> Result<Vec<Company>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let rows: Vec<CompanyRow> = sqlx::query_as(
+        "SELECT id, name, website, ceo, industry FROM companies ORDER BY name, website",
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch companies: {e}")))?;
+
+    rows.into_iter().map(TryFrom::try_from).collect()
+}
+
+/// Moves an application to another existing company, for when it was filed
+/// under the wrong employer. Only the company reference changes; the old
+/// company is removed if it has no applications left.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn reassign_application(
+    application_id: Uuid,
+    target_company_id: Uuid,
+) -> Result<(), ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut tx = pool.begin().await?;
+
+    let current: Option<String> =
+        sqlx::query_scalar("SELECT company_id FROM applications WHERE id = ?")
+            .bind(application_id.to_string())
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to fetch application: {e}")))?;
+    let current = current
+        .ok_or_else(|| ServerFnError::new(format!("Application {application_id} not found")))?;
+
+    let target_exists: bool =
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM companies WHERE id = ?)")
+            .bind(target_company_id.to_string())
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to fetch company: {e}")))?;
+    if !target_exists {
+        return Err(ServerFnError::new(format!(
+            "Company {target_company_id} not found"
+        )));
+    }
+    if current == target_company_id.to_string() {
+        return Ok(());
+    }
+
+    sqlx::query("UPDATE applications SET company_id = ?, updated_at = ? WHERE id = ?")
+        .bind(target_company_id.to_string())
+        .bind(date_to_db(OffsetDateTime::now_utc()))
+        .bind(application_id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to move application: {e}")))?;
+
+    // The delete trigger only prunes companies when an application goes away,
+    // so clean up after the move by hand
+    sqlx::query(
+        "DELETE FROM companies WHERE id = ? AND NOT EXISTS (SELECT 1 FROM applications WHERE company_id = ?)",
+    )
+    .bind(&current)
+    .bind(&current)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to remove empty company: {e}")))?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Maps free-text industry input to the id of the canonical industry whose
+/// name or alias it matches, ignoring case and whitespace. `None` files the
+/// company under "Other".
+///
+/// Names are compared through [`industry_key`] here rather than SQLite's
+/// `lower()`, which only folds ASCII; aliases are stored in that form already.
+#[cfg(feature = "ssr")]
+async fn resolve_industry(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    industry: &str,
+) -> Result<Option<String>, sqlx::Error> {
+    let key = industry_key(industry);
+
+    let industries: Vec<(String, String)> = sqlx::query_as("SELECT id, name FROM industries")
+        .fetch_all(&mut **tx)
+        .await?;
+    if let Some((id, _)) = industries
+        .into_iter()
+        .find(|(_, name)| industry_key(name) == key)
+    {
+        return Ok(Some(id));
+    }
+
+    sqlx::query_scalar("SELECT industry_id FROM industry_aliases WHERE alias = ?")
+        .bind(key)
+        .fetch_optional(&mut **tx)
+        .await
+}
+
+/// Lists the canonical industries, for suggesting them while typing.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_industries() -> Result<Vec<String>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    sqlx::query_scalar("SELECT name FROM industries ORDER BY name")
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to fetch industries: {e}")))
+}
+
+/// Averages, per company, the days from applying to the first response
+/// (Pending, Accepted or Rejected), based on the status history. An
+/// application counts as sent once it first reaches one of the statuses the
+/// preferences count as applied.
+///
+/// Companies are matched by name, ignoring case. Only companies that have
+/// responded at least once are returned; applications still waiting for a
+/// response are counted as `awaiting` rather than dropped silently.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_company_response_times() -> Result<Vec<CompanyResponseTime>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let preferences = load_preferences(&pool).await?;
+
+    let rows: Vec<(String, String, String, String)> = sqlx::query_as(
+        r#"
+        SELECT c.name, h.application_id, h.status, h.changed_at
+        FROM status_history h
+        JOIN applications a ON h.application_id = a.id
+        JOIN companies c ON a.company_id = c.id
+        ORDER BY h.application_id, h.changed_at, h.id
+        "#,
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch status history: {e}")))?;
+
+    // When each application was first sent, and first answered after that
+    let mut applications = std::collections::BTreeMap::<
+        String,
+        (String, Option<OffsetDateTime>, Option<OffsetDateTime>),
+    >::new();
+    for (company, application_id, status, changed_at) in rows {
+        let status: Status = status.parse().map_err(|e: String| ServerFnError::new(e))?;
+        let changed_at = date_from_db(&changed_at)?;
+        let (_, applied, responded) = applications
+            .entry(application_id)
+            .or_insert_with(|| (company, None, None));
+        // The change that sends an application is not also its response
+        if applied.is_none() {
+            if preferences.counts_as_applied(status) {
+                *applied = Some(changed_at);
+            }
+        } else if responded.is_none() && status.is_response() {
+            *responded = Some(changed_at);
+        }
+    }
+
+    let mut by_company = std::collections::BTreeMap::<String, CompanyResponseTime>::new();
+    for (company, applied, responded) in applications.into_values() {
+        let Some(applied) = applied else { continue };
+        let entry = by_company
+            .entry(company.trim().to_lowercase())
+            .or_insert_with(|| CompanyResponseTime {
+                company: company.trim().to_string(),
+                average_days: 0.0,
+                responses: 0,
+                awaiting: 0,
+            });
+        match responded {
+            Some(responded) => {
+                let days = (responded - applied).as_seconds_f64() / 86_400.0;
+                let n = f64::from(entry.responses);
+                entry.average_days = (entry.average_days * n + days) / (n + 1.0);
+                entry.responses += 1;
+            }
+            None => entry.awaiting += 1,
+        }
+    }
+
+    let mut times: Vec<_> = by_company
+        .into_values()
+        .filter(|t| t.responses > 0)
+        .collect();
+    times.sort_by(|a, b| a.average_days.total_cmp(&b.average_days));
+
+    Ok(times)
+}
+
+/// Counts active applications per status and per canonical industry. How
+/// many were sent and answered follows from the status counts, see
+/// [`Preferences::applied_and_responded`].
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_dashboard_stats() -> Result<DashboardStats, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let by_status: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT status, COUNT(*) FROM applications WHERE archived_at IS NULL GROUP BY status",
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to count statuses: {e}")))?;
+
+    let by_industry: Vec<(String, i64)> = sqlx::query_as(
+        r#"
+        SELECT COALESCE(i.name, 'Other') AS industry, COUNT(*) AS count
+        FROM applications a
+        JOIN companies c ON a.company_id = c.id
+        LEFT JOIN industries i ON c.industry_id = i.id
+        WHERE a.archived_at IS NULL
+        GROUP BY i.id
+        ORDER BY count DESC, i.id IS NULL, industry
+        "#,
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to count industries: {e}")))?;
+
+    let by_status: Vec<(Status, i64)> = Status::ALL
+        .into_iter()
+        .map(|status| {
+            let count = by_status
+                .iter()
+                .find(|(s, _)| s == status.as_str())
+                .map_or(0, |(_, count)| *count);
+            (status, count)
+        })
+        .collect();
+
+    let by_industry = by_industry
+        .into_iter()
+        .map(|(industry, count)| IndustryCount { industry, count })
+        .collect();
+
+    Ok(DashboardStats {
+        by_status,
+        by_industry,
+    })
+}
+
+/// How fast applications go out: the weekly rate over the last
+/// [`VELOCITY_WINDOW_WEEKS`] weeks against the same span before it. Archived
+/// applications count too, as they were sent all the same.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_velocity_trend() -> Result<VelocityTrend, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let preferences = load_preferences(&pool).await?;
+
+    let rows: Vec<(String, String)> = sqlx::query_as("SELECT date, status FROM applications")
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to fetch application dates: {e}")))?;
+
+    let today = OffsetDateTime::now_utc().date();
+    let window_days = VELOCITY_WINDOW_WEEKS * 7;
+    let (mut recent, mut prior, mut oldest) = (0, 0, None::<i64>);
+    for (date, status) in rows {
+        let status: Status = status.parse().map_err(|e: String| ServerFnError::new(e))?;
+        if !preferences.counts_as_applied(status) {
+            continue;
+        }
+        let days = (today - date_from_db(&date)?.date()).whole_days();
+        oldest = Some(oldest.map_or(days, |oldest| oldest.max(days)));
+        if days < window_days {
+            recent += 1;
+        } else if days < 2 * window_days {
+            prior += 1;
+        }
+    }
+
+    let per_week = |count: u32| f64::from(count) / VELOCITY_WINDOW_WEEKS as f64;
+    // Without a full prior window, or anything sent in it, there is nothing
+    // fair to compare against
+    let change_percent = (oldest.is_some_and(|oldest| oldest >= 2 * window_days - 1) && prior > 0)
+        .then(|| (per_week(recent) - per_week(prior)) / per_week(prior) * 100.0);
+
+    Ok(VelocityTrend {
+        recent_per_week: per_week(recent),
+        prior_per_week: per_week(prior),
+        change_percent,
+    })
+}
+
+/// Counts active applications per [`AgeBucket`], by how many days ago they
+/// were sent.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_age_distribution() -> Result<Vec<AgeCount>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let dates: Vec<String> =
+        sqlx::query_scalar("SELECT date FROM applications WHERE archived_at IS NULL")
+            .fetch_all(&pool)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to fetch application dates: {e}")))?;
+
+    let today = OffsetDateTime::now_utc().date();
+    let mut counts = AgeBucket::ALL.map(|bucket| AgeCount { bucket, count: 0 });
+    for date in dates {
+        let days = (today - date_from_db(&date)?.date()).whole_days();
+        if let Some(entry) = counts.iter_mut().find(|c| c.bucket.contains(days)) {
+            entry.count += 1;
+        }
+    }
+
+    Ok(counts.into())
+}
+
+/// How many rows [`recompute_derived`] loads at a time.
+#[cfg(feature = "ssr")]
+const RECOMPUTE_BATCH_SIZE: i64 = 500;
+
+/// Repairs the columns that are derived from others and can drift after
+/// direct database edits, imports or migrations:
+///
+/// - a company's `industry_id`, from its free-text industry;
+/// - a company's `website`, in normalized form;
+/// - an application's `updated_at`, which is never before its date or its
+///   last status change;
+/// - the status history, which starts with every application's creation;
+/// - companies left without any application.
+///
+/// Rows are processed in batches, all in one transaction.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn recompute_derived() -> Result<RecomputeReport, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let mut tx = pool.begin().await?;
+    let mut report = RecomputeReport::default();
+
+    let mut offset = 0;
+    loop {
+        let companies: Vec<(String, String, String, Option<String>)> = sqlx::query_as(
+            "SELECT id, website, industry, industry_id FROM companies ORDER BY id LIMIT ? OFFSET ?",
+        )
+        .bind(RECOMPUTE_BATCH_SIZE)
+        .bind(offset)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to fetch companies: {e}")))?;
+
+        for (id, website, industry, industry_id) in &companies {
+            let resolved = resolve_industry(&mut tx, industry).await?;
+            if resolved != *industry_id {
+                sqlx::query("UPDATE companies SET industry_id = ? WHERE id = ?")
+                    .bind(&resolved)
+                    .bind(id)
+                    .execute(&mut *tx)
+                    .await?;
+                report.industries_relinked += 1;
+            }
+
+            // A website that no longer parses is left for the user to fix
+            match normalize_website(website) {
+                Ok(normalized) if normalized != *website => {
+                    sqlx::query("UPDATE companies SET website = ? WHERE id = ?")
+                        .bind(normalized)
+                        .bind(id)
+                        .execute(&mut *tx)
+                        .await?;
+                    report.websites_normalized += 1;
+                }
+                Ok(_) => {}
+                Err(_) => report.invalid_websites += 1,
+            }
+        }
+
+        if (companies.len() as i64) < RECOMPUTE_BATCH_SIZE {
+            break;
+        }
+        offset += RECOMPUTE_BATCH_SIZE;
+    }
+
+    let mut offset = 0;
+    loop {
+        let applications: Vec<(String, String, String, String, Option<String>)> = sqlx::query_as(
+            r#"
+            SELECT a.id, a.status, a.date, a.updated_at, MAX(h.changed_at)
+            FROM applications a
+            LEFT JOIN status_history h ON h.application_id = a.id
+            GROUP BY a.id
+            ORDER BY a.id
+            LIMIT ? OFFSET ?
+            "#,
+        )
+        .bind(RECOMPUTE_BATCH_SIZE)
+        .bind(offset)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to fetch applications: {e}")))?;
+
+        for (id, status, date, updated_at, last_change) in &applications {
+            let id = parse_application_id(id)?;
+            let date = date_from_db(date)?;
+
+            let Some(last_change) = last_change else {
+                let status: Status = status.parse().map_err(|e: String| ServerFnError::new(e))?;
+                record_status_change(&mut tx, id, status, date, Some(RECOMPUTED)).await?;
+                report.histories_seeded += 1;
+                continue;
+            };
+
+            let earliest = date.max(date_from_db(last_change)?);
+            if date_from_db(updated_at)? < earliest {
+                sqlx::query("UPDATE applications SET updated_at = ? WHERE id = ?")
+                    .bind(date_to_db(earliest))
+                    .bind(id.to_string())
+                    .execute(&mut *tx)
+                    .await?;
+                report.updated_at_repaired += 1;
+            }
+        }
+
+        if (applications.len() as i64) < RECOMPUTE_BATCH_SIZE {
+            break;
+        }
+        offset += RECOMPUTE_BATCH_SIZE;
+    }
+
+    report.orphan_companies_removed = sqlx::query(
+        "DELETE FROM companies WHERE NOT EXISTS (SELECT 1 FROM applications a WHERE a.company_id = companies.id)",
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to remove orphan companies: {e}")))?
+    .rows_affected();
+
+    tx.commit().await?;
+    Ok(report)
+}
+
+/// The history reason of entries added by [`recompute_derived`].
+#[cfg(feature = "ssr")]
+const RECOMPUTED: &str = "recomputed";
+
+/// Finds companies that were entered under different names but point to the
+/// same website, as candidates for merging.
+///
+/// Groups are ordered by how many applications they cover, and companies
+/// within a group by their own application count.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_duplicate_websites() -> Result<Vec<DuplicateWebsiteGroup>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let rows: Vec<CompanyUsageRow> = sqlx::query_as(
+        r#"
+        SELECT c.id, c.name, c.website, c.ceo, c.industry,
+               COUNT(a.id) as application_count
+        FROM companies c
+        LEFT JOIN applications a ON a.company_id = c.id
+        GROUP BY c.id
+        "#,
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch companies: {e}")))?;
+
+    let mut by_website = std::collections::BTreeMap::<String, Vec<CompanyUsage>>::new();
+    for row in rows {
+        let usage = CompanyUsage::try_from(row)?;
+        let key = website_key(&usage.company.website);
+        if !key.is_empty() {
+            by_website.entry(key).or_default().push(usage);
+        }
+    }
+
+    let mut groups: Vec<DuplicateWebsiteGroup> = by_website
+        .into_iter()
+        .filter(|(_, companies)| companies.len() > 1)
+        .map(|(website, mut companies)| {
+            companies.sort_by_key(|c| std::cmp::Reverse(c.application_count));
+            DuplicateWebsiteGroup { website, companies }
+        })
+        .collect();
+    groups.sort_by_key(|g| {
+        std::cmp::Reverse(g.companies.iter().map(|c| c.application_count).sum::<i64>())
+    });
+
+    Ok(groups)
+}
+
+/// Finds live applications that are likely entered twice: applications to
+/// the same company dated within `thresholds.window_days` of the next one.
+/// With `thresholds.match_website`, companies sharing a website count as the
+/// same company.
+///
+/// Clusters are ordered largest first, and applications within a cluster
+/// oldest first.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn find_duplicate_applications(
+    thresholds: DuplicateThresholds,
+) -> Result<Vec<DuplicateCluster>, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    let rows: Vec<ApplicationRow> = sqlx::query_as(&format!(
+        "{SELECT_APPLICATIONS} WHERE a.archived_at IS NULL ORDER BY a.date"
+    ))
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch applications: {e}")))?;
+
+    let mut by_company = std::collections::BTreeMap::<String, Vec<AllApplicationsResponse>>::new();
+    for row in rows {
+        let application = AllApplicationsResponse::try_from(row)?;
+        let website = website_key(&application.company.website);
+        let key = if thresholds.match_website && !website.is_empty() {
+            website
+        } else {
+            application.company.id.to_string()
+        };
+        by_company.entry(key).or_default().push(application);
+    }
+
+    // Rows come sorted by date, so each application only needs comparing
+    // with the one before it
+    let window = time::Duration::days(i64::from(thresholds.window_days));
+    let mut clusters = Vec::new();
+    for applications in by_company.into_values() {
+        let mut cluster: Vec<AllApplicationsResponse> = Vec::new();
+        for application in applications {
+            if cluster
+                .last()
+                .is_some_and(|last| application.date - last.date > window)
+            {
+                clusters.push(std::mem::take(&mut cluster));
+            }
+            cluster.push(application);
+        }
+        clusters.push(cluster);
+    }
+
+    let mut clusters: Vec<DuplicateCluster> = clusters
+        .into_iter()
+        .filter(|applications| applications.len() > 1)
+        .map(|applications| DuplicateCluster { applications })
+        .collect();
+    clusters.sort_by_key(|c| std::cmp::Reverse(c.applications.len()));
+
+    Ok(clusters)
+}
+
+/// Folds the applications in `duplicates` into `keep` and deletes them,
+/// returning how many were deleted. Their attachments, share links and
+/// reminders move over to `keep`, and their notes are appended to its own.
+/// Their status history is deleted with them, as it would otherwise mix
+/// into the history of `keep`. Every duplicate has to be a live application.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err, fields(keep = %keep)))]
+async fn merge_duplicate_applications(
+    keep: Uuid,
+    duplicates: Vec<Uuid>,
+) -> Result<u64, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+
+    if duplicates.is_empty() {
+        return Ok(0);
+    }
+    if duplicates.contains(&keep) {
+        return Err(ServerFnError::new(
+            "The application to keep cannot also be merged away",
+        ));
+    }
+
+    let mut tx = pool.begin().await?;
+
+    let notes: Option<String> =
+        sqlx::query_scalar("SELECT notes FROM applications WHERE id = ? AND archived_at IS NULL")
+            .bind(keep.to_string())
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to fetch application: {e}")))?;
+    let mut notes =
+        notes.ok_or_else(|| ServerFnError::new(format!("Application {keep} not found")))?;
+
+    let mut query = QueryBuilder::<Sqlite>::new(
+        "SELECT id, notes FROM applications WHERE archived_at IS NULL AND id IN (",
+    );
+    let mut separated = query.separated(", ");
+    for id in &duplicates {
+        separated.push_bind(id.to_string());
+    }
+    separated.push_unseparated(") ORDER BY date");
+    let merged: Vec<(String, String)> = query
+        .build_query_as()
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to fetch applications: {e}")))?;
+    if let Some(missing) = duplicates
+        .iter()
+        .find(|id| !merged.iter().any(|(found, _)| *found == id.to_string()))
+    {
+        return Err(ServerFnError::new(format!(
+            "Application {missing} not found"
+        )));
+    }
+    for merged in merged
+        .iter()
+        .map(|(_, n)| n.trim())
+        .filter(|n| !n.is_empty())
+    {
+        if !notes.trim().is_empty() {
+            notes.push_str("\n\n");
+        }
+        notes.push_str(merged);
+    }
+
+    let mut query =
+        QueryBuilder::<Sqlite>::new("DELETE FROM status_history WHERE application_id IN (");
+    let mut separated = query.separated(", ");
+    for id in &duplicates {
+        separated.push_bind(id.to_string());
+    }
+    separated.push_unseparated(")");
+    query
+        .build()
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to delete history: {e}")))?;
+
+    for table in ["attachments", "share_links", "reminders"] {
+        let mut query =
+            QueryBuilder::<Sqlite>::new(format!("UPDATE {table} SET application_id = "));
+        query
+            .push_bind(keep.to_string())
+            .push(" WHERE application_id IN (");
+        let mut separated = query.separated(", ");
+        for id in &duplicates {
+            separated.push_bind(id.to_string());
+        }
+        separated.push_unseparated(")");
+        query
+            .build()
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to merge {table}: {e}")))?;
+    }
+
+    sqlx::query("UPDATE applications SET notes = ?, updated_at = ? WHERE id = ?")
+        .bind(notes)
+        .bind(date_to_db(OffsetDateTime::now_utc()))
+        .bind(keep.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to update application: {e}")))?;
+
+    let mut query = QueryBuilder::<Sqlite>::new("DELETE FROM applications WHERE id IN (");
+    let mut separated = query.separated(", ");
+    for id in &duplicates {
+        separated.push_bind(id.to_string());
+    }
+    separated.push_unseparated(")");
+    let deleted = query
+        .build()
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to delete applications: {e}")))?
+        .rows_affected();
+
+    tx.commit().await?;
+    Ok(deleted)
+}
+
+/// Returns up to `limit` events from every application, newest first,
+/// skipping the first `offset`: creations, status changes, note edits and
+/// archiving.
+/// `limit` is capped at [`TIMELINE_PAGE_SIZE`].
+///
+/// The first history entry of an application is its creation, so it is
+/// reported as such rather than as a status change.
+#[server]
+#[cfg_attr(feature = "ssr", tracing::instrument(ret, err))]
+async fn get_activity_timeline(limit: u32, offset: u32) -> Result<ActivityPage, ServerFnError> {
+    let pool = expect_context::<SqlitePool>();
+    let limit = limit.min(TIMELINE_PAGE_SIZE);
+
+    // One extra row tells whether there is another page
+    let mut rows: Vec<ActivityRow> = sqlx::query_as(
+        r#"
+        SELECT CASE WHEN h.id = (
+                   SELECT MIN(first.id) FROM status_history first
+                   WHERE first.application_id = h.application_id
+               ) THEN 'Created' ELSE 'StatusChanged' END AS kind,
+               h.application_id, c.name AS company, h.status, h.changed_at AS at
+        FROM status_history h
+        JOIN applications a ON h.application_id = a.id
+        JOIN companies c ON a.company_id = c.id
+        UNION ALL
+        SELECT 'NotesEdited', n.application_id, c.name, a.status, n.edited_at
+        FROM note_edits n
+        JOIN applications a ON n.application_id = a.id
+        JOIN companies c ON a.company_id = c.id
+        UNION ALL
+        SELECT 'Archived', a.id, c.name, a.status, a.archived_at
+        FROM applications a
+        JOIN companies c ON a.company_id = c.id
+        WHERE a.archived_at IS NOT NULL
+        ORDER BY at DESC
+        LIMIT ? OFFSET ?
+        "#,
+    )
+    .bind(limit.saturating_add(1))
+    .bind(offset)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| ServerFnError::new(format!("Failed to fetch activity: {e}")))?;
+
+    let has_more = rows.len() > limit as usize;
+    rows.truncate(limit as usize);
+
+    Ok(ActivityPage {
+        events: rows
+            .into_iter()
+            .map(TryFrom::try_from)
+            .collect::<Result<_, _>>()?,
+        has_more,
+    })
+}
+
+/// Renders the home page of your application.
+#[component]
+fn HomePage() -> impl IntoView {
+    let delete = ServerAction::<DeleteApplication>::new();
+    let create = ServerMultiAction::<CreateApplication>::new();
+    let update_status = ServerAction::<UpdateApplicationStatus>::new();
+    let bulk_followup = ServerAction::<BulkSetFollowup>::new();
+    let bulk_followup_matching = ServerAction::<BulkSetFollowupMatching>::new();
+    let withdraw_others = ServerAction::<WithdrawOtherApplications>::new();
+    let bulk_status = ServerAction::<BulkUpdateStatus>::new();
+    let bulk_status_matching = ServerAction::<BulkUpdateStatusMatching>::new();
+    toast_errors(delete);
+    toast_errors(bulk_status);
+    toast_errors(bulk_status_matching);
+    toast_errors(update_status);
+    toast_errors(withdraw_others);
+    toast_errors(bulk_followup);
+    toast_errors(bulk_followup_matching);
+
+    // A multi action has no single value, so watch the latest submission
+    let toasts = expect_context::<Toasts>();
+    Effect::new(move |_| {
+        let latest = create.submissions().with(|s| s.last().map(|s| s.value()));
+        if let Some(Some(Err(e))) = latest.map(|value| value.get()) {
+            toasts.push(describe_error(&e));
+        }
+    });
+
+    let query = use_query_map();
+    let url_filter = Memo::new(move |_| ApplicationFilter::from_query(&query.read()));
+    let page = Memo::new(move |_| {
+        query
+            .read()
+            .get("page")
+            .and_then(|p| p.parse::<u32>().ok())
+            .unwrap_or(1)
+            .max(1)
+    });
+
+    // A page opened without a view in the URL shows the preferred one, until
+    // the URL gets a view of its own
+    let preferences = expect_context::<PreferencesContext>();
+    let preferred = RwSignal::new(url_filter.get_untracked() == ApplicationFilter::default());
+    Effect::watch(
+        move || url_filter.track(),
+        move |_, _, _| preferred.set(false),
+        false,
+    );
+    let filter = Memo::new(move |_| {
+        if preferred.get() {
+            preferences.get().default_view
+        } else {
+            url_filter.get()
+        }
+    });
+
+    // A selection only makes sense for the filter and page it was made on,
+    // as the toolbar only knows the statuses of the loaded rows
+    let selection = Selection::default();
+    provide_context(ShownStatuses::default());
+    let pending = PendingEdits::default();
+    provide_context(pending);
+    Effect::new(move |_| {
+        update_status.value().with(|value| match value {
+            Some(Ok(event)) => pending.settle_status(event.id, event.status),
+            Some(Err(_)) => pending.discard_latest_status(),
+            None => {}
+        })
+    });
+    Effect::watch(
+        move || (filter.track(), page.track()),
+        move |_, _, _| selection.clear(),
+        false,
+    );
+
+    // Cards apply their own status changes, so the list only needs reloading
+    // when the change can move a card out of the filter or to another spot
+    let status_version = move || {
+        let filter = filter.read();
+        let moved_others = update_status
+            .value()
+            .with(|v| matches!(v, Some(Ok(event)) if event.withdrawn > 0));
+        (filter.status.is_some() || filter.sort == SortOrder::Status || moved_others)
+            .then(|| update_status.version().get())
+    };
+    provide_context(Resource::new(
+        move || {
+            (
+                (!preferred.get()).then(|| url_filter.get()),
+                page.get(),
+                delete.version().get(),
+                create.version().get(),
+                status_version(),
+                withdraw_others.version().get(),
+                bulk_status.version().get(),
+                bulk_status_matching.version().get(),
+                bulk_followup.version().get(),
+                bulk_followup_matching.version().get(),
+            )
+        },
+        move |(filter, page, ..)| async move {
+            // Waits for the preferred view rather than loading everything
+            // before it is known
+            let filter = match filter {
+                Some(filter) => filter,
+                None => match preferences.changed.get_untracked() {
+                    Some(changed) => changed.default_view,
+                    None => preferences.stored.await.unwrap_or_default().default_view,
+                },
+            };
+            get_all_applications(filter, page).await
+        },
+    ));
+    provide_context(filter);
+    provide_context(selection);
+    provide_context(bulk_followup);
+    provide_context(bulk_followup_matching);
+    provide_context(bulk_status);
+    provide_context(bulk_status_matching);
+    provide_context(create);
+    provide_context(delete);
+    provide_context(update_status);
+    provide_context(withdraw_others);
+
+    view! {
+        <h1>"Job Applications"</h1>
+        <AcceptedOfferPrompt />
+        <NextActionCard />
+        <Dashboard />
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            <ApplicationList />
+        </Suspense>
+    }
+}
+
+/// A prominent card with the one application [`get_next_action`] suggests.
+#[component]
+fn NextActionCard() -> impl IntoView {
+    let create = expect_context::<ServerMultiAction<CreateApplication>>();
+    let delete = expect_context::<ServerAction<DeleteApplication>>();
+    let update_status = expect_context::<ServerAction<UpdateApplicationStatus>>();
+    let bulk_status = expect_context::<ServerAction<BulkUpdateStatus>>();
+    let bulk_status_matching = expect_context::<ServerAction<BulkUpdateStatusMatching>>();
+    let bulk_followup = expect_context::<ServerAction<BulkSetFollowup>>();
+    let next = Resource::new(
+        move || {
+            (
+                create.version().get(),
+                delete.version().get(),
+                update_status.version().get(),
+                bulk_status.version().get(),
+                bulk_status_matching.version().get(),
+                bulk_followup.version().get(),
+            )
+        },
+        |_| get_next_action(),
+    );
+
+    view! {
+        <Transition fallback=|| ()>
+            {move || Suspend::new(async move {
+                let NextAction { application, reason } = next.await.ok().flatten()?;
+                Some(
+                    view! {
+                        <div class="next-action">
+                            <h2>"Do this next"</h2>
+                            <A href=format!("/applications/{}", application.id)>
+                                {application.company.name}
+                            </A>
+                            <span class=format!(
+                                "status-badge {}",
+                                application.status.css_class(),
+                            )>{application.status.label()}</span>
+                            <p>{reason}</p>
+                        </div>
+                    },
+                )
+            })}
+        </Transition>
+    }
+}
+
+/// Asks whether to withdraw the other active applications after one was
+/// accepted, when the preferences ask first.
+#[component]
+fn AcceptedOfferPrompt() -> impl IntoView {
+    let update_status = expect_context::<ServerAction<UpdateApplicationStatus>>();
+    let withdraw_others = expect_context::<ServerAction<WithdrawOtherApplications>>();
+    let prompt = RwSignal::new(None::<(Uuid, u32)>);
+    Effect::new(move |_| {
+        if let Some(Ok(event)) = update_status.value().get() {
+            prompt.set((event.withdrawable > 0).then_some((event.id, event.withdrawable)));
+        }
+    });
+
+    move || {
+        prompt.get().map(|(accepted_id, count)| {
+            view! {
+                <div class="accepted-offer-prompt" role="alertdialog">
+                    <p>
+                        {format!(
+                            "Congratulations! Withdraw the {count} other active application(s)?",
+                        )}
+                    </p>
+                    <button
+                        class="btn-submit"
+                        disabled=move || withdraw_others.pending().get()
+                        on:click=move |_| {
+                            withdraw_others.dispatch(WithdrawOtherApplications { accepted_id });
+                            prompt.set(None);
+                        }
+                    >
+                        "Withdraw them"
+                    </button>
+                    <button class="btn-secondary" on:click=move |_| prompt.set(None)>
+                        "Keep them"
+                    </button>
+                </div>
+            }
+        })
+    }
+}
+
+/// After a follow-up is done, offers to apply the status the preferences map
+/// it onto, or to undo that status when it was applied right away.
+#[component]
+fn FollowUpAdvancePrompt() -> impl IntoView {
+    let update_status = expect_context::<ServerAction<UpdateApplicationStatus>>();
+    let complete_follow_up = expect_context::<ServerAction<CompleteFollowUp>>();
+    let undo_advance = expect_context::<ServerAction<UndoFollowUpAdvance>>();
+    let prompt = RwSignal::new(None::<FollowUpCompletion>);
+    Effect::new(move |_| {
+        if let Some(Ok(completion)) = complete_follow_up.value().get() {
+            prompt.set(completion.advance.is_some().then_some(completion));
+        }
+    });
+
+    move || {
+        prompt.get().and_then(|completion| {
+            let advance = completion.advance?;
+            let FollowUpCompletion {
+                id,
+                previous,
+                follow_up,
+                applied,
+                ..
+            } = completion;
+            let (message, confirm) = if applied {
+                (
+                    format!(
+                        "Moved {} to {} now that its follow-up is done.",
+                        completion.company,
+                        advance.label(),
+                    ),
+                    format!("Undo, keep it {}", previous.label()),
+                )
+            } else {
+                (
+                    format!(
+                        "Follow-up done. Move {} to {}?",
+                        completion.company,
+                        advance.label(),
+                    ),
+                    format!("Move to {}", advance.label()),
+                )
+            };
+            Some(view! {
+                <div class="accepted-offer-prompt" role="alertdialog">
+                    <p>{message}</p>
+                    <button
+                        class="btn-submit"
+                        disabled=move || {
+                            update_status.pending().get() || undo_advance.pending().get()
+                        }
+                        on:click=move |_| {
+                            if applied {
+                                undo_advance
+                                    .dispatch(UndoFollowUpAdvance {
+                                        id,
+                                        previous,
+                                        follow_up,
+                                    });
+                            } else {
+                                update_status
+                                    .dispatch(UpdateApplicationStatus {
+                                        id: id.to_string(),
+                                        status: advance,
+                                    });
+                            }
+                            prompt.set(None);
+                        }
+                    >
+                        {confirm}
+                    </button>
+                    <button class="btn-secondary" on:click=move |_| prompt.set(None)>
+                        "Dismiss"
+                    </button>
+                </div>
+            })
+        })
+    }
+}
+
+/// Summarizes the active applications by status and industry.
+#[component]
+fn Dashboard() -> impl IntoView {
+    let create = expect_context::<ServerMultiAction<CreateApplication>>();
+    let delete = expect_context::<ServerAction<DeleteApplication>>();
+    let update_status = expect_context::<ServerAction<UpdateApplicationStatus>>();
+    let bulk_status = expect_context::<ServerAction<BulkUpdateStatus>>();
+    let bulk_status_matching = expect_context::<ServerAction<BulkUpdateStatusMatching>>();
+    let preferences = expect_context::<PreferencesContext>();
+    let versions = move || {
+        (
+            create.version().get(),
+            delete.version().get(),
+            update_status.version().get(),
+            bulk_status.version().get(),
+            bulk_status_matching.version().get(),
+            preferences.save.version().get(),
+        )
+    };
+    let stats = Resource::new(versions, |_| get_dashboard_stats());
+    let deadlines = Resource::new(versions, |_| get_upcoming_deadlines());
+
+    // Once the list holds every live application, the status counts follow
+    // the cards, optimistic changes included, instead of waiting on the
+    // stats to be refetched. Other views still come from the server.
+    let applications = expect_context::<Resource<Result<ApplicationPage, ServerFnError>>>();
+    let filter = expect_context::<Memo<ApplicationFilter>>();
+    let shown = expect_context::<ShownStatuses>();
+    let local_by_status = Memo::new(move |_| {
+        if !filter.read().matches_everything() {
+            return None;
+        }
+        let page = applications.get()?.ok()?;
+        if page.total as usize != page.applications.len() {
+            return None;
+        }
+        let shown = shown.0.read();
+        let statuses: Vec<Status> = page
+            .applications
+            .iter()
+            .map(|a| shown.get(&a.id).copied().unwrap_or(a.status))
+            .collect();
+        Some(
+            Status::ALL
+                .into_iter()
+                .map(|status| {
+                    (
+                        status,
+                        statuses.iter().filter(|s| **s == status).count() as i64,
+                    )
+                })
+                .collect::<Vec<_>>(),
+        )
+    });
+
+    view! {
+        <section class="dashboard">
+            <Transition fallback=|| ()>
+                {move || Suspend::new(async move {
+                    stats
+                        .await
+                        .ok()
+                        .map(|stats| {
+                            view! {
+                                <div class="dashboard-widget">
+                                    <h2>"By status"</h2>
+                                    <StatusCounts by_status=stats.by_status local=local_by_status />
+                                </div>
+                                <div class="dashboard-widget">
+                                    <h2>"By industry"</h2>
+                                    <ul class="stat-list">
+                                        {stats
+                                            .by_industry
+                                            .into_iter()
+                                            .map(|row| {
+                                                view! {
+                                                    <li>
+                                                        <span>{row.industry}</span>
+                                                        <strong>{row.count}</strong>
+                                                    </li>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </ul>
+                                </div>
+                            }
+                        })
+                })}
+            </Transition>
+            <ResponseTimes />
+            <Velocity />
+            <AgeDistribution />
+            <UnconfirmedSubmissions />
+            <DueReminders />
+            <Transition fallback=|| ()>
+                {move || Suspend::new(async move {
+                    deadlines
+                        .await
+                        .ok()
+                        .filter(|list| !list.is_empty())
+                        .map(|list| {
+                            let today = OffsetDateTime::now_utc().date();
+                            view! {
+                                <div class="dashboard-widget">
+                                    <h2>"Closing soon"</h2>
+                                    <ul class="stat-list">
+                                        {list
+                                            .into_iter()
+                                            .map(|application| {
+                                                let badge = application
+                                                    .deadline
+                                                    .and_then(|deadline| {
+                                                        deadline_badge(application.status, deadline, today)
+                                                    });
+                                                view! {
+                                                    <li>
+                                                        <A href=format!(
+                                                            "/applications/{}",
+                                                            application.id,
+                                                        )>{application.company.name}</A>
+                                                        {badge
+                                                            .map(|(label, class)| {
+                                                                view! { <span class=class>{label}</span> }
+                                                            })}
+                                                    </li>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </ul>
+                                </div>
+                            }
+                        })
+                })}
+            </Transition>
+        </section>
+    }
+}
+
+/// The status counts of the dashboard, taken from `local` whenever it has
+/// them and from the server's `by_status` otherwise.
+#[component]
+fn StatusCounts(
+    by_status: Vec<(Status, i64)>,
+    local: Memo<Option<Vec<(Status, i64)>>>,
+) -> impl IntoView {
+    let preferences = expect_context::<PreferencesContext>();
+
+    move || {
+        let by_status = local.get().unwrap_or_else(|| by_status.clone());
+        let (applied, responded) = preferences.get().applied_and_responded(&by_status);
+        view! {
+            <ul class="stat-list">
+                {by_status
+                    .into_iter()
+                    .map(|(status, count)| {
+                        view! {
+                            <li>
+                                <span class=format!(
+                                    "status-badge {}",
+                                    status.css_class(),
+                                )>{status.label()}</span>
+                                <strong>{count}</strong>
+                            </li>
+                        }
+                    })
+                    .collect_view()}
+            </ul>
+            <p class="stat-summary">
+                {format!("{applied} applied, {responded} answered")}
+                {(applied > 0)
+                    .then(|| format!(" ({:.0}%)", responded as f64 * 100.0 / applied as f64))}
+            </p>
+        }
+    }
+}
+
+/// Sent applications still without a confirmation of receipt, to check they
+/// went through, each with a button to mark the confirmation as received.
+#[component]
+fn UnconfirmedSubmissions() -> impl IntoView {
+    let create = expect_context::<ServerMultiAction<CreateApplication>>();
+    let update_status = expect_context::<ServerAction<UpdateApplicationStatus>>();
+    let bulk_status = expect_context::<ServerAction<BulkUpdateStatus>>();
+    let bulk_status_matching = expect_context::<ServerAction<BulkUpdateStatusMatching>>();
+    let set_confirmation = ServerAction::<SetConfirmationReceived>::new();
+    toast_errors(set_confirmation);
+    let unconfirmed = Resource::new(
+        move || {
+            (
+                create.version().get(),
+                update_status.version().get(),
+                bulk_status.version().get(),
+                bulk_status_matching.version().get(),
+                set_confirmation.version().get(),
+            )
+        },
+        |_| get_unconfirmed_submissions(),
+    );
+
+    view! {
+        <Transition fallback=|| ()>
+            {move || Suspend::new(async move {
+                unconfirmed
+                    .await
+                    .ok()
+                    .filter(|list| !list.is_empty())
+                    .map(|list| {
+                        view! {
+                            <div class="dashboard-widget">
+                                <h2>"Verify submission"</h2>
+                                <ul class="stat-list">
+                                    {list
+                                        .into_iter()
+                                        .map(|application| {
+                                            let id = application.id;
+                                            view! {
+                                                <li>
+                                                    <A href=format!(
+                                                        "/applications/{id}",
+                                                    )>{application.company.name}</A>
+                                                    <button
+                                                        class="btn-secondary"
+                                                        disabled=move || set_confirmation.pending().get()
+                                                        on:click=move |_| {
+                                                            set_confirmation
+                                                                .dispatch(SetConfirmationReceived {
+                                                                    id,
+                                                                    received: true,
+                                                                });
+                                                        }
+                                                    >
+                                                        "Confirmed"
+                                                    </button>
+                                                </li>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </ul>
+                            </div>
+                        }
+                    })
+            })}
+        </Transition>
+    }
+}
+
+/// Open reminders that fell due, each with a button to mark it as done.
+#[component]
+fn DueReminders() -> impl IntoView {
+    let set_done = ServerAction::<SetReminderDone>::new();
+    toast_errors(set_done);
+    let due = Resource::new(move || set_done.version().get(), |_| get_due_reminders());
+
+    view! {
+        <Transition fallback=|| ()>
+            {move || Suspend::new(async move {
+                due.await
+                    .ok()
+                    .filter(|list| !list.is_empty())
+                    .map(|list| {
+                        view! {
+                            <div class="dashboard-widget">
+                                <h2>"Due reminders"</h2>
+                                <ul class="stat-list">
+                                    {list
+                                        .into_iter()
+                                        .map(|DueReminder { reminder, company }| {
+                                            let id = reminder.id;
+                                            view! {
+                                                <li>
+                                                    <span>
+                                                        <A href=format!(
+                                                            "/applications/{}",
+                                                            reminder.application_id,
+                                                        )>{company}</A>
+                                                        <span class="reminder-due">
+                                                            {format!(" {}: {}", reminder.remind_at, reminder.text)}
+                                                        </span>
+                                                    </span>
+                                                    <button
+                                                        class="btn-secondary"
+                                                        disabled=move || set_done.pending().get()
+                                                        on:click=move |_| {
+                                                            set_done
+                                                                .dispatch(SetReminderDone {
+                                                                    id,
+                                                                    done: true,
+                                                                });
+                                                        }
+                                                    >
+                                                        "Done"
+                                                    </button>
+                                                </li>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </ul>
+                            </div>
+                        }
+                    })
+            })}
+        </Transition>
+    }
+}
+
+/// The weekly application rate, with an arrow for how it moved since the
+/// weeks before.
+#[component]
+fn Velocity() -> impl IntoView {
+    let create = expect_context::<ServerMultiAction<CreateApplication>>();
+    let delete = expect_context::<ServerAction<DeleteApplication>>();
+    let update_status = expect_context::<ServerAction<UpdateApplicationStatus>>();
+    let preferences = expect_context::<PreferencesContext>();
+    let trend = Resource::new(
+        move || {
+            (
+                create.version().get(),
+                delete.version().get(),
+                update_status.version().get(),
+                preferences.save.version().get(),
+            )
+        },
+        |_| get_velocity_trend(),
+    );
+
+    view! {
+        <Transition fallback=|| ()>
+            {move || Suspend::new(async move {
+                let trend = trend.await.ok()?;
+                Some(
+                    view! {
+                        <div class="dashboard-widget velocity">
+                            <h2>"Velocity"</h2>
+                            <p class="velocity-rate">
+                                {format!("{:.1}", trend.recent_per_week)}
+                                <span>" per week"</span>
+                            </p>
+                            {match (trend.direction(), trend.change_percent) {
+                                (Some(direction), Some(change)) => {
+                                    view! {
+                                        <p class="stat-summary">
+                                            <span class=direction.css_class()>
+                                                {format!("{} {change:+.0}%", direction.arrow())}
+                                            </span>
+                                            {format!(
+                                                " from {:.1} per week in the {VELOCITY_WINDOW_WEEKS} weeks before",
+                                                trend.prior_per_week,
+                                            )}
+                                        </p>
+                                    }
+                                        .into_any()
+                                }
+                                _ => {
+                                    view! {
+                                        <p class="stat-summary">
+                                            {format!(
+                                                "Not enough history yet to compare against the {VELOCITY_WINDOW_WEEKS} weeks before.",
+                                            )}
+                                        </p>
+                                    }
+                                        .into_any()
+                                }
+                            }}
+                        </div>
+                    },
+                )
+            })}
+        </Transition>
+    }
+}
+
+/// A table of how quickly each company answers, sortable by column.
+#[component]
+fn ResponseTimes() -> impl IntoView {
+    let update_status = expect_context::<ServerAction<UpdateApplicationStatus>>();
+    let preferences = expect_context::<PreferencesContext>();
+    let times = Resource::new(
+        move || {
+            (
+                update_status.version().get(),
+                preferences.save.version().get(),
+            )
+        },
+        |_| get_company_response_times(),
+    );
+    let sort = RwSignal::new(ResponseTimeSort::Fastest);
+
+    let header = move |label: &'static str, by: ResponseTimeSort| {
+        view! {
+            <th
+                class="sortable"
+                class:sorted=move || sort.get() == by
+                on:click=move |_| sort.set(by)
+            >
+                {label}
+            </th>
+        }
+    };
+
+    view! {
+        <Transition fallback=|| ()>
+            {move || Suspend::new(async move {
+                let times = times.await.ok().filter(|times| !times.is_empty())?;
+                let rows = move || {
+                    let mut times = times.clone();
+                    match sort.get() {
+                        ResponseTimeSort::Company => {
+                            times.sort_by_key(|t| t.company.to_lowercase())
+                        }
+                        ResponseTimeSort::Fastest => {
+                            times.sort_by(|a, b| a.average_days.total_cmp(&b.average_days))
+                        }
+                        ResponseTimeSort::Responses => {
+                            times.sort_by_key(|t| std::cmp::Reverse(t.responses))
+                        }
+                    }
+                    times
+                        .into_iter()
+                        .map(|t| {
+                            view! {
+                                <tr>
+                                    <td>{t.company}</td>
+                                    <td>{format!("{:.1} days", t.average_days)}</td>
+                                    <td>
+                                        {t.responses}
+                                        {(t.awaiting > 0)
+                                            .then(|| {
+                                                view! {
+                                                    <span class="report-count">
+                                                        {format!(" ({} awaiting)", t.awaiting)}
+                                                    </span>
+                                                }
+                                            })}
+                                    </td>
+                                </tr>
+                            }
+                        })
+                        .collect_view()
+                };
+                Some(
+                    view! {
+                        <div class="dashboard-widget response-times">
+                            <h2>"Response times"</h2>
+                            <table>
+                                <thead>
+                                    <tr>
+                                        {header("Company", ResponseTimeSort::Company)}
+                                        {header("Average", ResponseTimeSort::Fastest)}
+                                        {header("Responses", ResponseTimeSort::Responses)}
+                                    </tr>
+                                </thead>
+                                <tbody>{rows}</tbody>
+                            </table>
+                        </div>
+                    },
+                )
+            })}
+        </Transition>
+    }
+}
+
+/// Bars of how long ago the active applications were sent. Each bar links to
+/// the list filtered to that date range.
+#[component]
+fn AgeDistribution() -> impl IntoView {
+    let create = expect_context::<ServerMultiAction<CreateApplication>>();
+    let delete = expect_context::<ServerAction<DeleteApplication>>();
+    let filter = expect_context::<Memo<ApplicationFilter>>();
+    let counts = Resource::new(
+        move || (create.version().get(), delete.version().get()),
+        |_| get_age_distribution(),
+    );
+
+    view! {
+        <Transition fallback=|| ()>
+            {move || Suspend::new(async move {
+                let counts = counts.await.ok()?;
+                let largest = counts.iter().map(|c| c.count).max().unwrap_or(0).max(1);
+                let today = OffsetDateTime::now_utc().date();
+                Some(
+                    view! {
+                        <div class="dashboard-widget age-distribution">
+                            <h2>"Age"</h2>
+                            <ul class="age-bars">
+                                {counts
+                                    .into_iter()
+                                    .map(|AgeCount { bucket, count }| {
+                                        let (from, to) = bucket.date_range(today);
+                                        let href = format!(
+                                            "/{}",
+                                            ApplicationFilter {
+                                                from: from.and_then(|d| d.format(DB_DATE).ok()),
+                                                to: to.and_then(|d| d.format(DB_DATE).ok()),
+                                                ..filter.get_untracked()
+                                            }
+                                                .to_query_string(),
+                                        );
+                                        view! {
+                                            <li>
+                                                <A href=href>
+                                                    <span class="age-label">{bucket.label()}</span>
+                                                    <span class="age-bar">
+                                                        <span style:width=format!(
+                                                            "{}%",
+                                                            count * 100 / largest,
+                                                        )></span>
+                                                    </span>
+                                                    <strong>{count}</strong>
+                                                </A>
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ul>
+                        </div>
+                    },
+                )
+            })}
+        </Transition>
+    }
+}
+
+#[component]
+fn ApplicationList() -> impl IntoView {
+    let applications = expect_context::<Resource<Result<ApplicationPage, ServerFnError>>>();
+    let filter = expect_context::<Memo<ApplicationFilter>>();
+    let selection = expect_context::<Selection>();
+
+    // A filtered view can be empty too, but only a truly empty tracker needs the tour
+    let is_empty = Signal::derive(move || {
+        filter.read() == ApplicationFilter::default()
+            && matches!(applications.get(), Some(Ok(page)) if page.total == 0)
+    });
+
+    let visible_ids = Memo::new(move |_| {
+        applications
+            .get()
+            .and_then(Result::ok)
+            .map(|page| page.applications.iter().map(|a| a.id).collect::<Vec<_>>())
+            .unwrap_or_default()
+    });
+    let all_visible_selected = move || {
+        let visible = visible_ids.read();
+        !visible.is_empty() && visible.iter().all(|id| selection.ids.read().contains(id))
+    };
+    let on_select_visible = move |ev: web_sys::Event| {
+        if event_target_checked(&ev) {
+            selection.ids.set(visible_ids.get().into_iter().collect());
+        } else {
+            selection.clear();
+        }
+    };
+
+    view! {
+        <Tour steps=ONBOARDING_TOUR storage_key="soulcrush.onboarding-dismissed" when=is_empty />
+        <CreateApplicationForm />
+        <div class="list-view">
+            // Pinned while scrolling; the header shares the rows' column template
+            <div class="list-pinned">
+                <FilterToolbar />
+                <SelectionToolbar />
+                <div class="list-header">
+                    <span>
+                        <input
+                            type="checkbox"
+                            class="card-select"
+                            title="Select all on this page"
+                            prop:checked=move || {
+                                selection.all_matching.get() || all_visible_selected()
+                            }
+                            disabled=move || selection.all_matching.get()
+                            on:change=on_select_visible
+                        />
+                    </span>
+                    <span>"Company"</span>
+                    <span>"Industry"</span>
+                    <span>"Link"</span>
+                    <span>"Dates"</span>
+                    <span>"Status"</span>
+                    <span>"Action"</span>
+                </div>
+            </div>
+            <div class="application-list">
+                <Suspense fallback=|| ()>
+                    <ErrorBoundary fallback=move |errors| {
+                        #[cfg(feature = "ssr")]
+                        for (_, e) in errors.get_untracked().iter() {
+                            tracing::error!("Failed to render the application list: {e}");
+                        }
+                        view! {
+                            <div class="render-error" role="alert">
+                                <p>"Part of the list could not be shown."</p>
+                                <button
+                                    class="btn-secondary"
+                                    on:click=move |_| {
+                                        errors.set(Errors::default());
+                                        applications.refetch();
+                                    }
+                                >
+                                    "Retry"
+                                </button>
+                            </div>
+                        }
+                    }>
+                        {move || Suspend::new(async move {
+                            match applications.await {
+                                Ok(page) => {
+                                    let pending = expect_context::<PendingEdits>();
+                                    let applications: Vec<_> = page
+                                        .applications
+                                        .iter()
+                                        .cloned()
+                                        .map(|application| pending.reconcile(application))
+                                        .collect();
+                                    view! {
+                                        <For
+                                            each=move || applications.clone()
+                                            // Rebuild a card whose application changed elsewhere,
+                                            // as when it was withdrawn for an accepted offer
+                                            key=|s| (s.id, s.updated_at)
+                                            let:application
+                                        >
+                                            <ApplicationCard application />
+                                        </For>
+                                        <Pager page />
+                                    }
+                                        .into_any()
+                                }
+                                Err(e) => {
+                                    view! { <div class="error">{describe_error(&e)}</div> }.into_any()
+                                }
+                            }
+                        })}
+                    </ErrorBoundary>
+                </Suspense>
+            </div>
+        </div>
+    }
+}
+
+/// Links to the neighbouring pages of the list, keeping the filter.
+#[component]
+fn Pager(page: ApplicationPage) -> impl IntoView {
+    let filter = expect_context::<Memo<ApplicationFilter>>();
+    let page_count = page.page_count();
+    let href = move |to: u32| {
+        let mut query = filter.get_untracked().to_query();
+        if to > 1 {
+            query.insert("page", to.to_string());
+        }
+        format!("/{}", query.to_query_string())
+    };
+
+    (page_count > 1).then(|| {
+        view! {
+            <nav class="pager">
+                {(page.page > 1).then(|| view! { <A href=href(page.page - 1)>"◀ Prev"</A> })}
+                <span>{format!("Page {} of {page_count}", page.page)}</span>
+                {(page.page < page_count)
+                    .then(|| view! { <A href=href(page.page + 1)>"Next ▶"</A> })}
+            </nav>
+        }
+    })
+}
+
+#[component]
+fn ApplicationCard(application: AllApplicationsResponse) -> impl IntoView {
+    let delete_action = expect_context::<ServerAction<DeleteApplication>>();
+    let update_status_action = expect_context::<ServerAction<UpdateApplicationStatus>>();
+    let selection = expect_context::<Selection>();
+    let filter = expect_context::<Memo<ApplicationFilter>>();
+
+    let id = application.id;
+    // When the server last changed the application, as far as this card knows
+    let changed_at = RwSignal::new(application.updated_at);
+    let name = application.company.name.clone();
+    let industry = application.company.industry.clone();
+    // What the server last said, under any change still waiting on it
+    let settled = RwSignal::new(application.status);
+    let pending = expect_context::<PendingEdits>();
+    let status = Signal::derive(move || pending.status(id).unwrap_or_else(|| settled.get()));
+    let shown = expect_context::<ShownStatuses>();
+    Effect::new(move |_| {
+        let status = status.get();
+        shown.0.update(|shown| {
+            shown.insert(id, status);
+        });
+    });
+
+    Effect::new(move |_| {
+        update_status_action.value().with(|value| {
+            if let Some(Ok(event)) = value {
+                if event.id == id {
+                    settled.set(event.status);
+                    changed_at.set(event.updated_at);
+                }
+            }
+        })
+    });
+    let is_selected = move || selection.all_matching.get() || selection.ids.read().contains(&id);
+
+    let on_select = move |ev: web_sys::Event| {
+        let checked = event_target_checked(&ev);
+        selection.ids.update(|ids| {
+            if checked {
+                ids.insert(id);
+            } else {
+                ids.remove(&id);
+            }
+        });
+    };
+
+    let on_status_change = move |ev: web_sys::Event| {
+        let target = event_target::<web_sys::HtmlSelectElement>(&ev);
+        if let Ok(new_status) = target.value().parse::<Status>() {
+            pending.set_status(id, new_status, changed_at.get_untracked());
+            update_status_action.dispatch(UpdateApplicationStatus {
+                id: id.to_string(),
+                status: new_status,
+            });
+        }
+    };
+
+    view! {
+        <div class="application-card" class:selected=is_selected>
+            <input
+                type="checkbox"
+                class="card-select"
+                title="Select"
+                prop:checked=is_selected
+                disabled=move || selection.all_matching.get()
+                on:change=on_select
+            />
+            <A href=format!("/applications/{id}") attr:class="card-company">
+                <span
+                    class="completeness-ring"
+                    style=format!("--completeness: {}%", application.completeness)
+                    title=format!("{}% of the details filled in", application.completeness)
+                ></span>
+                {move || highlighted(&name, filter.read().search.as_deref())}
+            </A>
+            <span class="card-industry">
+                {move || highlighted(&industry, filter.read().search.as_deref())}
+            </span>
+            <a href=application.company.website.clone() target="_blank" class="card-link">
+                "Visit"
+            </a>
+            <span class="card-followup">
+                {application.follow_up.map(|date| format!("Follow up {date}"))}
+                {move || {
+                    let today = OffsetDateTime::now_utc().date();
+                    application
+                        .deadline
+                        .and_then(|deadline| deadline_badge(status.get(), deadline, today))
+                        .map(|(label, class)| view! { <span class=class>{label}</span> })
+                }}
+            </span>
+            <select
+                class=move || format!("status-select {}", status.get().css_class())
+                on:change=on_status_change
+            >
+                <option value="ToDo" selected=move || status.get() == Status::ToDo>"To Do"</option>
+                <option value="Solicitated" selected=move || status.get() == Status::Solicitated>"Applied"</option>
+                <option value="Pending" selected=move || status.get() == Status::Pending>"Pending"</option>
+                <option value="Accepted" selected=move || status.get() == Status::Accepted>"Accepted"</option>
+                <option value="Rejected" selected=move || status.get() == Status::Rejected>"Rejected"</option>
+                <option value="Withdrawn" selected=move || status.get() == Status::Withdrawn>"Withdrawn"</option>
             </select>
             <ActionForm action=delete_action attr:class="card-delete">
                 <input type="hidden" name="id" value=id.to_string() />
@@ -257,125 +4709,3833 @@ fn ApplicationCard(application: AllApplicationsResponse) -> impl IntoView {
     }
 }
 
-#[component]
-fn CreateApplicationForm() -> impl IntoView {
-    let create_action = expect_context::<ServerMultiAction<CreateApplication>>();
-    let is_open = RwSignal::new(false);
+/// Renders `text` with every occurrence of `query` wrapped in `<mark>`. The
+/// pieces are plain text nodes, so markup in a company name stays inert.
+fn highlighted(text: &str, query: Option<&str>) -> Vec<AnyView> {
+    let mut parts = Vec::new();
+    let mut end = 0;
+    for range in match_ranges(text, query.unwrap_or_default()) {
+        parts.push(text[end..range.start].to_string().into_any());
+        parts.push(view! { <mark>{text[range.clone()].to_string()}</mark> }.into_any());
+        end = range.end;
+    }
+    parts.push(text[end..].to_string().into_any());
+    parts
+}
+
+/// Byte ranges of the non-overlapping occurrences of `query` in `text`.
+/// Like SQLite's `LIKE`, this ignores case for ASCII letters only, so the
+/// highlights agree with what the search matched.
+fn match_ranges(text: &str, query: &str) -> Vec<std::ops::Range<usize>> {
+    let (text, query) = (text.as_bytes(), query.as_bytes());
+    let mut ranges = Vec::new();
+    if query.is_empty() {
+        return ranges;
+    }
+
+    // Only ASCII bytes are folded, so a match of a whole UTF-8 query can never
+    // start or end inside a multi-byte character
+    let mut start = 0;
+    while start + query.len() <= text.len() {
+        if text[start..start + query.len()].eq_ignore_ascii_case(query) {
+            ranges.push(start..start + query.len());
+            start += query.len();
+        } else {
+            start += 1;
+        }
+    }
+    ranges
+}
+
+/// One step of a [`Tour`]: the element it points at and what it explains.
+#[derive(Clone, Copy)]
+struct TourStep {
+    /// CSS selector of the element to highlight. The step is still shown,
+    /// without a highlight, when nothing matches.
+    target: &'static str,
+    title: &'static str,
+    body: &'static str,
+}
+
+const ONBOARDING_TOUR: &[TourStep] = &[
+    TourStep {
+        target: ".form-toggle",
+        title: "Add an application",
+        body: "Open this form to log a company you want to apply to, or one you already applied to.",
+    },
+    TourStep {
+        target: ".application-list",
+        title: "Track the status",
+        body: "Every application lands in this list with a status dropdown. Move it along from To Do to Applied, Pending and beyond.",
+    },
+    TourStep {
+        target: ".filter-toolbar",
+        title: "Find things fast",
+        body: "Search, filter by status or date, and save the views you use often.",
+    },
+];
+
+/// A dismissible, step-by-step overlay that highlights parts of the page.
+///
+/// It shows while `when` is true, until the user finishes or dismisses it.
+/// That is remembered under `storage_key` in `localStorage`, so it never
+/// comes back in the same browser.
+#[component]
+fn Tour(
+    steps: &'static [TourStep],
+    storage_key: &'static str,
+    #[prop(into)] when: Signal<bool>,
+) -> impl IntoView {
+    let step = RwSignal::new(0);
+    // Only the browser knows whether the tour was dismissed, so the server
+    // renders it hidden and the client reveals it after hydration
+    let dismissed = RwSignal::new(true);
+    Effect::new(move |_| dismissed.set(tour_dismissed(storage_key)));
+
+    let active = move || when.get() && !dismissed.get() && step.get() < steps.len();
+
+    Effect::new(move |highlighted: Option<Option<web_sys::Element>>| {
+        if let Some(Some(element)) = highlighted {
+            let _ = element.class_list().remove_1("tour-highlight");
+        }
+        if !active() {
+            return None;
+        }
+        let element = document()
+            .query_selector(steps[step.get()].target)
+            .ok()
+            .flatten();
+        if let Some(element) = &element {
+            let _ = element.class_list().add_1("tour-highlight");
+            element.scroll_into_view();
+        }
+        element
+    });
+
+    let dismiss = move || {
+        dismissed.set(true);
+        remember_tour_dismissed(storage_key);
+    };
+    let next = move |_| {
+        if step.get() + 1 >= steps.len() {
+            dismiss();
+        } else {
+            step.update(|s| *s += 1);
+        }
+    };
+
+    view! {
+        <Show when=active>
+            {move || {
+                let current = steps[step.get()];
+                let is_last = step.get() + 1 == steps.len();
+                view! {
+                    <div class="tour-backdrop" on:click=move |_| dismiss()></div>
+                    <div class="tour-panel" role="dialog" aria-label="Getting started">
+                        <span class="tour-progress">
+                            {format!("{}/{}", step.get() + 1, steps.len())}
+                        </span>
+                        <h3>{current.title}</h3>
+                        <p>{current.body}</p>
+                        <div class="tour-actions">
+                            <button class="btn-secondary" on:click=move |_| dismiss()>
+                                "Dismiss tour"
+                            </button>
+                            <button class="btn-submit" on:click=next>
+                                {if is_last { "Done" } else { "Next" }}
+                            </button>
+                        </div>
+                    </div>
+                }
+            }}
+        </Show>
+    }
+}
+
+fn tour_dismissed(storage_key: &str) -> bool {
+    window()
+        .local_storage()
+        .ok()
+        .flatten()
+        .and_then(|storage| storage.get_item(storage_key).ok().flatten())
+        .is_some()
+}
+
+fn remember_tour_dismissed(storage_key: &str) {
+    if let Ok(Some(storage)) = window().local_storage() {
+        let _ = storage.set_item(storage_key, "1");
+    }
+}
+
+/// Actions on the applications ticked in the list, shown while any are ticked.
+#[component]
+fn SelectionToolbar() -> impl IntoView {
+    let selection = expect_context::<Selection>();
+    let applications = expect_context::<Resource<Result<ApplicationPage, ServerFnError>>>();
+    let filter = expect_context::<Memo<ApplicationFilter>>();
+    let bulk_followup = expect_context::<ServerAction<BulkSetFollowup>>();
+    let bulk_followup_matching = expect_context::<ServerAction<BulkSetFollowupMatching>>();
+    let bulk_status = expect_context::<ServerAction<BulkUpdateStatus>>();
+    let bulk_status_matching = expect_context::<ServerAction<BulkUpdateStatusMatching>>();
+    let followup_date = RwSignal::new(String::new());
+    let target_status = RwSignal::new(None::<Status>);
+    let confirming = RwSignal::new(false);
+
+    let page = move || applications.get().and_then(Result::ok);
+    let total = move || page().map_or(0, |page| page.total);
+    // Offer to extend the selection only once the whole page is ticked and
+    // more matches exist beyond it
+    let can_select_matching = move || {
+        page().is_some_and(|page| {
+            !selection.all_matching.get()
+                && page.total as usize > page.applications.len()
+                && page
+                    .applications
+                    .iter()
+                    .all(|a| selection.ids.read().contains(&a.id))
+        })
+    };
+
+    let set_followup = move |date: Option<Date>| {
+        if selection.all_matching.get() {
+            bulk_followup_matching.dispatch(BulkSetFollowupMatching {
+                filter: filter.get(),
+                date,
+            });
+            return;
+        }
+        let ids: Vec<Uuid> = selection.ids.read().iter().copied().collect();
+        if !ids.is_empty() {
+            bulk_followup.dispatch(BulkSetFollowup { ids, date });
+        }
+    };
+    let on_set = move |_| {
+        if let Ok(date) = Date::parse(&followup_date.get(), DB_DATE) {
+            set_followup(Some(date));
+        }
+    };
+
+    // Ticked rows are all on the loaded page, so their current statuses are
+    // known without asking the server. All matches are counted by it.
+    let matching_counts = Resource::new(
+        move || (confirming.get() && selection.all_matching.get()).then(|| filter.get()),
+        |filter| async move {
+            match filter {
+                Some(filter) => count_matching_statuses(filter).await.ok(),
+                None => None,
+            }
+        },
+    );
+    let status_summary = move || {
+        let target = target_status.get()?;
+        let counts = if selection.all_matching.get() {
+            matching_counts.get().flatten()?
+        } else {
+            let mut counts = HashMap::new();
+            for application in page()?.applications {
+                if selection.ids.read().contains(&application.id) {
+                    *counts.entry(application.status).or_default() += 1;
+                }
+            }
+            counts
+        };
+        Some(bulk_status_summary(&counts, target))
+    };
+    let on_confirm_status = move |_| {
+        confirming.set(false);
+        let Some(status) = target_status.get() else {
+            return;
+        };
+        if selection.all_matching.get() {
+            bulk_status_matching.dispatch(BulkUpdateStatusMatching {
+                filter: filter.get(),
+                status,
+            });
+            return;
+        }
+        let ids: Vec<Uuid> = selection.ids.read().iter().copied().collect();
+        if !ids.is_empty() {
+            bulk_status.dispatch(BulkUpdateStatus { ids, status });
+        }
+    };
+
+    let compare_href = move || match selection.ids.read().iter().collect::<Vec<_>>()[..] {
+        [a, b] => format!("/compare?a={a}&b={b}"),
+        _ => "/".to_string(),
+    };
+
+    view! {
+        <Show when=move || selection.all_matching.get() || !selection.ids.read().is_empty()>
+            <div class="selection-toolbar">
+                <span class="selection-count">
+                    {move || {
+                        if selection.all_matching.get() {
+                            format!("All {} matching selected", total())
+                        } else {
+                            format!("{} on this page selected", selection.ids.read().len())
+                        }
+                    }}
+                </span>
+                <Show when=can_select_matching>
+                    <button
+                        class="btn-secondary"
+                        on:click=move |_| selection.all_matching.set(true)
+                    >
+                        {move || format!("Select all {} matching", total())}
+                    </button>
+                </Show>
+                <input type="date" bind:value=followup_date />
+                <button
+                    class="btn-secondary"
+                    disabled=move || followup_date.read().is_empty()
+                    on:click=on_set
+                >
+                    "Set follow-up"
+                </button>
+                <button class="btn-secondary" on:click=move |_| set_followup(None)>
+                    "Clear follow-up"
+                </button>
+                <select
+                    on:change=move |ev| {
+                        confirming.set(false);
+                        target_status.set(event_target_value(&ev).parse().ok());
+                    }
+                >
+                    <option value="" selected=move || target_status.get().is_none()>
+                        "Change status to…"
+                    </option>
+                    {Status::ALL
+                        .into_iter()
+                        .map(|status| {
+                            view! {
+                                <option
+                                    value=status.as_str()
+                                    selected=move || target_status.get() == Some(status)
+                                >
+                                    {status.label()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+                <button
+                    class="btn-secondary"
+                    disabled=move || {
+                        target_status.get().is_none() || bulk_status.pending().get()
+                            || bulk_status_matching.pending().get()
+                    }
+                    on:click=move |_| confirming.set(true)
+                >
+                    "Change status"
+                </button>
+                <Show when=move || {
+                    !selection.all_matching.get() && selection.ids.read().len() == 2
+                }>
+                    <A href=compare_href attr:class="btn-secondary">
+                        "Compare"
+                    </A>
+                </Show>
+                <button class="btn-secondary" on:click=move |_| selection.clear()>
+                    "Deselect"
+                </button>
+            </div>
+            <Show when=move || confirming.get()>
+                <div class="bulk-confirm" role="alertdialog">
+                    <p>{status_summary}</p>
+                    <button class="btn-submit" on:click=on_confirm_status>
+                        "Confirm"
+                    </button>
+                    <button class="btn-secondary" on:click=move |_| confirming.set(false)>
+                        "Cancel"
+                    </button>
+                </div>
+            </Show>
+        </Show>
+    }
+}
+
+/// Describes what moving applications counted by current status in `counts`
+/// to `target` does, calling out each status they are moved away from, e.g.
+/// "12 applications will change to Rejected, including 2 currently Accepted".
+fn bulk_status_summary(counts: &HashMap<Status, usize>, target: Status) -> String {
+    let unchanged = counts.get(&target).copied().unwrap_or(0);
+    let changing = counts.values().sum::<usize>() - unchanged;
+    let plural = |n: usize| {
+        if n == 1 {
+            "application"
+        } else {
+            "applications"
+        }
+    };
+
+    let mut summary = format!(
+        "{changing} {} will change to {}",
+        plural(changing),
+        target.label()
+    );
+    let from: Vec<String> = Status::ALL
+        .into_iter()
+        .filter(|s| *s != target)
+        .filter_map(|s| {
+            let count = counts.get(&s).copied().unwrap_or(0);
+            (count > 0).then(|| format!("{count} currently {}", s.label()))
+        })
+        .collect();
+    if !from.is_empty() {
+        summary.push_str(", including ");
+        summary.push_str(&from.join(", "));
+    }
+    if unchanged > 0 {
+        summary.push_str(&format!(
+            ". {unchanged} {} already {}.",
+            plural(unchanged),
+            if unchanged == 1 { "is" } else { "are" }
+        ));
+    } else {
+        summary.push('.');
+    }
+    summary
+}
+
+/// Search, status, date range and sort controls for the list, plus saved presets.
+///
+/// The filter lives in the URL query, so every control just navigates to an
+/// updated query string.
+#[component]
+fn FilterToolbar() -> impl IntoView {
+    let filter = expect_context::<Memo<ApplicationFilter>>();
+    let navigate = use_navigate();
+    let apply = Callback::new(move |filter: ApplicationFilter| {
+        navigate(
+            &format!("/{}", filter.to_query_string()),
+            NavigateOptions {
+                replace: true,
+                ..Default::default()
+            },
+        )
+    });
+    let update = move |change: fn(&mut ApplicationFilter, String)| {
+        move |ev: web_sys::Event| {
+            let mut next = filter.get();
+            change(&mut next, event_target_value(&ev));
+            apply.run(next);
+        }
+    };
+
+    let save_preset = ServerAction::<SaveFilterPreset>::new();
+    let delete_preset = ServerAction::<DeleteFilterPreset>::new();
+    toast_errors(save_preset);
+    toast_errors(delete_preset);
+    let presets = Resource::new(
+        move || (save_preset.version().get(), delete_preset.version().get()),
+        |_| get_filter_presets(),
+    );
+    let selected_preset = RwSignal::new(None::<Uuid>);
+    let preset_name = RwSignal::new(String::new());
+
+    let on_save = move |_| {
+        save_preset.dispatch(SaveFilterPreset {
+            name: preset_name.get(),
+            filter: filter.get(),
+        });
+        preset_name.set(String::new());
+    };
+    let on_delete = move |_| {
+        if let Some(id) = selected_preset.get() {
+            delete_preset.dispatch(DeleteFilterPreset { id });
+            selected_preset.set(None);
+        }
+    };
+
+    view! {
+        <div class="filter-toolbar">
+            <div class="filter-controls">
+                <input
+                    type="search"
+                    class="filter-search"
+                    placeholder="Search company or industry"
+                    prop:value=move || filter.get().search.unwrap_or_default()
+                    on:input=update(|f, v| f.search = Some(v).filter(|v| !v.is_empty()))
+                />
+                <label class="filter-toggle" title="Also search the notes">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || filter.read().search_notes
+                        on:change=move |ev| {
+                            let mut next = filter.get();
+                            next.search_notes = event_target_checked(&ev);
+                            apply.run(next);
+                        }
+                    />
+                    "Notes"
+                </label>
+                <select on:change=update(|f, v| f.status = v.parse().ok())>
+                    <option value="" selected=move || filter.get().status.is_none()>
+                        "All statuses"
+                    </option>
+                    {Status::ALL
+                        .into_iter()
+                        .map(|status| {
+                            view! {
+                                <option
+                                    value=status.as_str()
+                                    selected=move || filter.get().status == Some(status)
+                                >
+                                    {status.label()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+                <input
+                    type="date"
+                    title="Applied from"
+                    prop:value=move || filter.get().from.unwrap_or_default()
+                    on:change=update(|f, v| f.from = Some(v).filter(|v| !v.is_empty()))
+                />
+                <input
+                    type="date"
+                    title="Applied until"
+                    prop:value=move || filter.get().to.unwrap_or_default()
+                    on:change=update(|f, v| f.to = Some(v).filter(|v| !v.is_empty()))
+                />
+                <select on:change=update(|f, v| f.sort = v.parse().unwrap_or_default())>
+                    {SortOrder::ALL
+                        .into_iter()
+                        .map(|sort| {
+                            view! {
+                                <option value=sort.as_str() selected=move || filter.get().sort == sort>
+                                    {sort.label()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </div>
+            <div class="filter-presets">
+                <Suspense fallback=|| ()>
+                    {move || Suspend::new(async move {
+                        let presets = presets.await.unwrap_or_default();
+                        let lookup = presets.clone();
+                        let on_pick = move |ev: web_sys::Event| {
+                            let id = Uuid::parse_str(&event_target_value(&ev)).ok();
+                            selected_preset.set(id);
+                            if let Some(preset) = lookup.iter().find(|p| Some(p.id) == id) {
+                                apply.run(preset.filter.clone());
+                            }
+                        };
+                        view! {
+                            <select on:change=on_pick>
+                                <option value="" selected=move || selected_preset.get().is_none()>
+                                    "Saved views"
+                                </option>
+                                {presets
+                                    .into_iter()
+                                    .map(|preset| {
+                                        let id = preset.id;
+                                        view! {
+                                            <option
+                                                value=id.to_string()
+                                                selected=move || selected_preset.get() == Some(id)
+                                            >
+                                                {preset.name}
+                                            </option>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </select>
+                        }
+                    })}
+                </Suspense>
+                <button
+                    class="btn-delete"
+                    title="Delete saved view"
+                    disabled=move || selected_preset.get().is_none()
+                    on:click=on_delete
+                >
+                    "X"
+                </button>
+                <input type="text" placeholder="View name" bind:value=preset_name />
+                <button
+                    class="btn-secondary"
+                    disabled=move || preset_name.get().trim().is_empty()
+                    on:click=on_save
+                >
+                    "Save current view"
+                </button>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn CreateApplicationForm() -> impl IntoView {
+    let create_action = expect_context::<ServerMultiAction<CreateApplication>>();
+    let is_open = RwSignal::new(false);
+    let industries = Resource::new(|| (), |_| get_industries());
+
+    view! {
+        <div class="create-form-container">
+            <button class="form-toggle" class:closed=move || !is_open.get() on:click=move |_| is_open.update(|v| *v = !*v)>
+                <span class="toggle-icon" class:open=is_open>
+                    "▶"
+                </span>
+                "New Application"
+            </button>
+            <Show when=move || is_open.get()>
+                <MultiActionForm action=create_action attr:class="create-form">
+                    <div class="form-row">
+                        <div class="form-group">
+                            <label for="req[company][name]">"Company Name"</label>
+                            <input type="text" name="req[company][name]" required />
+                        </div>
+                        <div class="form-group">
+                            <label for="req[company][website]">"Website"</label>
+                            <WebsiteInput name="req[company][website]" />
+                        </div>
+                    </div>
+
+                    <div class="form-row">
+                        <div class="form-group">
+                            <label for="req[company][ceo]">"CEO"</label>
+                            <input type="text" name="req[company][ceo]" required />
+                        </div>
+                        <div class="form-group">
+                            <label for="req[company][industry]">"Industry"</label>
+                            <input
+                                type="text"
+                                name="req[company][industry]"
+                                list="industry-options"
+                                required
+                            />
+                            <datalist id="industry-options">
+                                <Suspense fallback=|| ()>
+                                    {move || Suspend::new(async move {
+                                        industries
+                                            .await
+                                            .unwrap_or_default()
+                                            .into_iter()
+                                            .map(|name| view! { <option value=name /> })
+                                            .collect_view()
+                                    })}
+                                </Suspense>
+                            </datalist>
+                        </div>
+                    </div>
+
+                    <div class="form-row form-actions">
+                        <div class="form-group">
+                            <label for="req[deadline]">"Deadline"</label>
+                            <input type="date" name="req[deadline]" />
+                        </div>
+                        <div class="form-group">
+                            <label for="req[status]">"Status"</label>
+                            <select name="req[status]">
+                                <option value="ToDo">"To Do"</option>
+                                <option value="Solicitated">"Applied"</option>
+                                <option value="Pending">"Pending"</option>
+                                <option value="Accepted">"Accepted"</option>
+                                <option value="Rejected">"Rejected"</option>
+                                <option value="Withdrawn">"Withdrawn"</option>
+                            </select>
+                        </div>
+                        <button type="submit" class="btn-submit">
+                            "Add Application"
+                        </button>
+                    </div>
+                </MultiActionForm>
+            </Show>
+        </div>
+    }
+}
+
+/// A website field that tidies its value on blur the same way the server
+/// does, and says live whether the current value will be accepted.
+#[component]
+fn WebsiteInput(name: &'static str) -> impl IntoView {
+    let value = RwSignal::new(String::new());
+    let validity = Memo::new(move |_| normalize_website(&value.read()).map(|_| ()));
+
+    let on_blur = move |_| {
+        if let Ok(normalized) = normalize_website(&value.get()) {
+            value.set(normalized);
+        }
+    };
+
+    view! {
+        <div class="website-input">
+            <input type="url" name=name required bind:value=value on:blur=on_blur />
+            <Show when=move || !value.read().is_empty()>
+                {move || match validity.get() {
+                    Ok(()) => view! { <span class="validity valid">"✓"</span> }.into_any(),
+                    Err(e) => {
+                        view! { <span class="validity invalid">{e.to_string()}</span> }.into_any()
+                    }
+                }}
+            </Show>
+        </div>
+    }
+}
+
+/// Shows a single application together with its status history.
+#[component]
+fn ApplicationDetail() -> impl IntoView {
+    let params = use_params_map();
+    let reassign = ServerAction::<ReassignApplication>::new();
+    toast_errors(reassign);
+    provide_context(reassign);
+    let detail = Resource::new(
+        move || (params.read().get("id"), reassign.version().get()),
+        |(id, _)| async move {
+            let id = id
+                .and_then(|id| Uuid::parse_str(&id).ok())
+                .ok_or_else(|| ServerFnError::new("Invalid application id"))?;
+            get_application_detail(id).await
+        },
+    );
+
+    view! {
+        <A href="/" attr:class="back-link">"◀ Back"</A>
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match detail.await {
+                    Ok(detail) => {
+                        let application = detail.application;
+                        view! {
+                            <h1>{application.company.name}</h1>
+                            <div class="detail-actions">
+                                <ChangeCompany
+                                    application_id=application.id
+                                    company_id=application.company.id
+                                />
+                                <ShareDialog application_id=application.id />
+                                <a
+                                    class="btn-secondary"
+                                    href=format!(
+                                        "/api/companies/{}/contacts.vcf",
+                                        application.company.id,
+                                    )
+                                    download
+                                >
+                                    "Export contacts"
+                                </a>
+                            </div>
+                            <div class="detail-panel">
+                                <dl class="detail-fields">
+                                    <dt>"Status"</dt>
+                                    <dd>
+                                        <span class=format!(
+                                            "status-badge {}",
+                                            application.status.css_class(),
+                                        )>{application.status.to_string()}</span>
+                                    </dd>
+                                    <dt>"Applied"</dt>
+                                    <dd>{application.date.date().to_string()}</dd>
+                                    {detail
+                                        .confirmation_received
+                                        .map(|received| {
+                                            view! {
+                                                <dt>"Confirmation"</dt>
+                                                <dd>
+                                                    <ConfirmationInput id=application.id received />
+                                                </dd>
+                                            }
+                                        })}
+                                    <dt>"Deadline"</dt>
+                                    <dd>
+                                        <DeadlineInput
+                                            id=application.id
+                                            deadline=application.deadline
+                                        />
+                                    </dd>
+                                    <dt>"Industry"</dt>
+                                    <dd>{application.company.industry.clone()}</dd>
+                                    <dt>"CEO"</dt>
+                                    <dd>{application.company.ceo}</dd>
+                                    <dt>"Website"</dt>
+                                    <dd>
+                                        <a
+                                            href=application.company.website.clone()
+                                            target="_blank"
+                                            class="card-link"
+                                        >
+                                            {application.company.website.clone()}
+                                        </a>
+                                    </dd>
+                                </dl>
+                            </div>
+                            <h2>"Notes"</h2>
+                            <NotesInput id=application.id notes=detail.notes />
+                            <h2>"History"</h2>
+                            <ol class="status-history">
+                                {detail
+                                    .history
+                                    .into_iter()
+                                    .map(|change| {
+                                        view! {
+                                            <li>
+                                                <span class=format!(
+                                                    "status-badge {}",
+                                                    change.status.css_class(),
+                                                )>{change.status.to_string()}</span>
+                                                <span class="history-date">
+                                                    {format!(
+                                                        "{} {:02}:{:02}",
+                                                        change.changed_at.date(),
+                                                        change.changed_at.hour(),
+                                                        change.changed_at.minute(),
+                                                    )}
+                                                </span>
+                                                {change
+                                                    .reason
+                                                    .map(|reason| {
+                                                        view! { <span class="history-reason">{reason}</span> }
+                                                    })}
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ol>
+                            <Reminders application_id=application.id />
+                            <AttachmentGallery
+                                application_id=application.id
+                                first_page=detail.attachments
+                            />
+                            <SimilarApplications
+                                id=application.id
+                                industry=application.company.industry.clone()
+                            />
+                        }
+                            .into_any()
+                    }
+                    Err(_) => {
+                        view! { <div class="error">"Application not found"</div> }.into_any()
+                    }
+                }
+            })}
+        </Suspense>
+    }
+}
+
+/// Two applications side by side, taken from the `a` and `b` query
+/// parameters, with the fields that differ highlighted.
+#[component]
+fn ComparePage() -> impl IntoView {
+    let query = use_query_map();
+    let details = Resource::new(
+        move || (query.read().get("a"), query.read().get("b")),
+        |(a, b)| async move {
+            let parse = |id: Option<String>| {
+                id.and_then(|id| Uuid::parse_str(&id).ok())
+                    .ok_or_else(|| ServerFnError::new("Invalid application id"))
+            };
+            let (a, b) = (parse(a)?, parse(b)?);
+            Ok::<_, ServerFnError>((
+                get_application_detail(a).await?,
+                get_application_detail(b).await?,
+            ))
+        },
+    );
+
+    view! {
+        <A href="/" attr:class="back-link">"◀ Back"</A>
+        <h1>"Compare"</h1>
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match details.await {
+                    Ok((a, b)) => {
+                        let rows = compared_fields(&a)
+                            .into_iter()
+                            .zip(compared_fields(&b))
+                            .map(|((label, a), (_, b))| {
+                                let differs = a != b;
+                                view! {
+                                    <tr class:compare-diff=differs>
+                                        <th scope="row">{label}</th>
+                                        <td>{a}</td>
+                                        <td>{b}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view();
+                        view! {
+                            <table class="compare-table">
+                                <thead>
+                                    <tr>
+                                        <th></th>
+                                        <th>
+                                            <A href=format!(
+                                                "/applications/{}",
+                                                a.application.id,
+                                            )>{a.application.company.name.clone()}</A>
+                                        </th>
+                                        <th>
+                                            <A href=format!(
+                                                "/applications/{}",
+                                                b.application.id,
+                                            )>{b.application.company.name.clone()}</A>
+                                        </th>
+                                    </tr>
+                                </thead>
+                                <tbody>{rows}</tbody>
+                            </table>
+                        }
+                            .into_any()
+                    }
+                    Err(e) => view! { <div class="error">{describe_error(&e)}</div> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+/// The rows of [`ComparePage`] for one application, as label and value.
+fn compared_fields(detail: &ApplicationDetailResponse) -> Vec<(&'static str, String)> {
+    let application = &detail.application;
+    let date = |date: Option<Date>| date.map_or_else(|| "—".to_string(), |d| d.to_string());
+    vec![
+        ("Company", application.company.name.clone()),
+        ("Industry", application.company.industry.clone()),
+        ("Status", application.status.label().to_string()),
+        ("Applied", application.date.date().to_string()),
+        ("Deadline", date(application.deadline)),
+        ("Follow-up", date(application.follow_up)),
+        ("Status changes", detail.history.len().to_string()),
+        ("Notes", detail.notes.clone()),
+    ]
+}
+
+/// Moves an application to another company, picked by name from the ones
+/// already known.
+#[component]
+fn ChangeCompany(application_id: Uuid, company_id: Uuid) -> impl IntoView {
+    let reassign = expect_context::<ServerAction<ReassignApplication>>();
+    let toasts = expect_context::<Toasts>();
+    let is_open = RwSignal::new(false);
+    let choice = RwSignal::new(String::new());
+    let companies = Resource::new(|| (), |_| get_companies());
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let choice = choice.get_untracked();
+        let target = companies
+            .get_untracked()
+            .and_then(Result::ok)
+            .unwrap_or_default()
+            .into_iter()
+            .find(|company| company_choice(company) == choice.trim());
+        match target {
+            Some(company) => {
+                reassign.dispatch(ReassignApplication {
+                    application_id,
+                    target_company_id: company.id,
+                });
+                is_open.set(false);
+            }
+            None => toasts.push(format!("No company matches {choice:?}")),
+        }
+    };
+
+    view! {
+        <Show
+            when=move || is_open.get()
+            fallback=move || {
+                view! {
+                    <button class="btn-secondary" on:click=move |_| is_open.set(true)>
+                        "Change company"
+                    </button>
+                }
+            }
+        >
+            <form class="change-company" on:submit=on_submit>
+                <input
+                    type="text"
+                    list="company-options"
+                    placeholder="Move to company"
+                    required
+                    bind:value=choice
+                />
+                <datalist id="company-options">
+                    <Suspense fallback=|| ()>
+                        {move || Suspend::new(async move {
+                            companies
+                                .await
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter(|company| company.id != company_id)
+                                .map(|company| view! { <option value=company_choice(&company) /> })
+                                .collect_view()
+                        })}
+                    </Suspense>
+                </datalist>
+                <button type="submit" class="btn-submit" disabled=move || reassign.pending().get()>
+                    "Move"
+                </button>
+                <button type="button" class="btn-secondary" on:click=move |_| is_open.set(false)>
+                    "Cancel"
+                </button>
+            </form>
+        </Show>
+    }
+}
+
+/// Creates, shows and revokes the public link to an application, with a QR
+/// code to scan it from a phone.
+#[component]
+fn ShareDialog(application_id: Uuid) -> impl IntoView {
+    let create = ServerAction::<CreateShareLink>::new();
+    let revoke = ServerAction::<RevokeShareLink>::new();
+    toast_errors(create);
+    toast_errors(revoke);
+    let is_open = RwSignal::new(false);
+    let link = Resource::new(
+        move || (create.version().get(), revoke.version().get()),
+        move |_| get_share_link(application_id),
+    );
+
+    view! {
+        <button class="btn-secondary" on:click=move |_| is_open.update(|v| *v = !*v)>
+            "Share"
+        </button>
+        <Show when=move || is_open.get()>
+            <div class="share-dialog" role="dialog" aria-label="Share application">
+                <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+                    {move || Suspend::new(async move {
+                        match link.await {
+                            Ok(Some(token)) => {
+                                let href = format!("/share/{token}");
+                                let link = href.clone();
+                                view! {
+                                    <img
+                                        class="share-qr"
+                                        src=format!("/api/share/{token}/qr.svg")
+                                        alt="QR code of the share link"
+                                    />
+                                    <a href=href target="_blank" class="card-link">
+                                        {link}
+                                    </a>
+                                    <button
+                                        class="btn-delete"
+                                        disabled=move || revoke.pending().get()
+                                        on:click=move |_| {
+                                            revoke.dispatch(RevokeShareLink { token: token.clone() });
+                                        }
+                                    >
+                                        "Revoke link"
+                                    </button>
+                                }
+                                    .into_any()
+                            }
+                            Ok(None) => {
+                                view! {
+                                    <p>"Anyone with the link can see the company and status."</p>
+                                    <button
+                                        class="btn-submit"
+                                        disabled=move || create.pending().get()
+                                        on:click=move |_| {
+                                            create.dispatch(CreateShareLink { application_id });
+                                        }
+                                    >
+                                        "Create share link"
+                                    </button>
+                                }
+                                    .into_any()
+                            }
+                            Err(e) => view! { <div class="error">{describe_error(&e)}</div> }.into_any(),
+                        }
+                    })}
+                </Suspense>
+            </div>
+        </Show>
+    }
+}
+
+/// The read-only view behind a share link.
+#[component]
+fn SharedApplicationPage() -> impl IntoView {
+    let params = use_params_map();
+    let shared = Resource::new(
+        move || params.read().get("token").unwrap_or_default(),
+        get_shared_application,
+    );
+
+    view! {
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match shared.await {
+                    Ok(shared) => {
+                        view! {
+                            <h1>{shared.company}</h1>
+                            <div class="detail-panel">
+                                <dl class="detail-fields">
+                                    <dt>"Status"</dt>
+                                    <dd>
+                                        <span class=format!(
+                                            "status-badge {}",
+                                            shared.status.css_class(),
+                                        )>{shared.status.label()}</span>
+                                    </dd>
+                                    <dt>"Applied"</dt>
+                                    <dd>{shared.date.date().to_string()}</dd>
+                                    <dt>"Industry"</dt>
+                                    <dd>{shared.industry}</dd>
+                                    <dt>"Website"</dt>
+                                    <dd>
+                                        <a href=shared.website.clone() target="_blank" class="card-link">
+                                            {shared.website.clone()}
+                                        </a>
+                                    </dd>
+                                </dl>
+                            </div>
+                        }
+                            .into_any()
+                    }
+                    Err(_) => {
+                        view! { <div class="error">"This link has been revoked or does not exist."</div> }
+                            .into_any()
+                    }
+                }
+            })}
+        </Suspense>
+    }
+}
+
+/// How a company is offered in the company picker. The website tells apart
+/// companies that share a name.
+fn company_choice(company: &Company) -> String {
+    format!("{} ({})", company.name, company.website)
+}
+
+/// The files attached to an application, a page at a time, with a form to
+/// upload more. The first page comes with the application's detail; later
+/// ones are fetched. Previews load lazily, only once they scroll into view.
+#[component]
+fn AttachmentGallery(application_id: Uuid, first_page: AttachmentPage) -> impl IntoView {
+    let page = RwSignal::new(1);
+    let attachments = Resource::new(
+        move || page.get(),
+        move |page| {
+            let first_page = first_page.clone();
+            async move {
+                if page == 1 {
+                    return Ok(first_page);
+                }
+                list_attachments(
+                    application_id,
+                    ATTACHMENTS_PAGE_SIZE,
+                    page_offset(page, ATTACHMENTS_PAGE_SIZE),
+                )
+                .await
+            }
+        },
+    );
+
+    view! {
+        <h2>"Attachments"</h2>
+        <form
+            class="attachment-upload"
+            method="post"
+            enctype="multipart/form-data"
+            action=format!("/api/applications/{application_id}/attachments")
+        >
+            <input type="file" name="file" required />
+            <button type="submit" class="btn-submit">
+                "Upload"
+            </button>
+        </form>
+        <Transition fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match attachments.await {
+                    Ok(attachments) => {
+                        let page_count = attachments.total.div_ceil(ATTACHMENTS_PAGE_SIZE).max(1);
+                        view! {
+                            <p class="attachment-quota">
+                                <meter
+                                    min="0"
+                                    max=attachments.quota_bytes.to_string()
+                                    value=attachments.used_bytes.to_string()
+                                ></meter>
+                                {format!(
+                                    "{} of {} used",
+                                    format_bytes(attachments.used_bytes),
+                                    format_bytes(attachments.quota_bytes),
+                                )}
+                            </p>
+                            <ul class="attachment-gallery">
+                                {attachments
+                                    .attachments
+                                    .into_iter()
+                                    .map(|attachment| view! { <AttachmentTile attachment /> })
+                                    .collect_view()}
+                            </ul>
+                            {(page_count > 1)
+                                .then(|| {
+                                    view! {
+                                        <nav class="pager">
+                                            <button
+                                                class="btn-secondary"
+                                                disabled=move || page.get() <= 1
+                                                on:click=move |_| page.update(|p| *p -= 1)
+                                            >
+                                                "◀ Prev"
+                                            </button>
+                                            <span>
+                                                {move || format!("Page {} of {page_count}", page.get())}
+                                            </span>
+                                            <button
+                                                class="btn-secondary"
+                                                disabled=move || page.get() >= page_count
+                                                on:click=move |_| page.update(|p| *p += 1)
+                                            >
+                                                "Next ▶"
+                                            </button>
+                                        </nav>
+                                    }
+                                })}
+                        }
+                            .into_any()
+                    }
+                    Err(e) => view! { <div class="error">{describe_error(&e)}</div> }.into_any(),
+                }
+            })}
+        </Transition>
+    }
+}
+
+#[component]
+fn AttachmentTile(attachment: Attachment) -> impl IntoView {
+    let src = format!("/api/attachments/{}", attachment.id);
+    let preview = match AttachmentPreview::of(&attachment.content_type) {
+        AttachmentPreview::Image => {
+            view! { <img src=src.clone() alt=attachment.file_name.clone() loading="lazy" /> }
+                .into_any()
+        }
+        AttachmentPreview::Pdf => view! {
+            <iframe
+                src=format!("{src}#page=1&toolbar=0&view=FitH")
+                title=attachment.file_name.clone()
+            ></iframe>
+        }
+        .into_any(),
+        AttachmentPreview::Download => {
+            view! { <span class="attachment-icon">"▤"</span> }.into_any()
+        }
+    };
+
+    view! {
+        <li class="attachment">
+            <div class="attachment-preview">{preview}</div>
+            <a href=src target="_blank" class="attachment-name">
+                {attachment.file_name}
+            </a>
+            <span class="attachment-meta">
+                {format!("{} · {}", format_bytes(attachment.size), attachment.uploaded_at.date())}
+            </span>
+        </li>
+    }
+}
+
+/// Writes a byte count the way file managers do, e.g. `1.5 MB`.
+fn format_bytes(bytes: i64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// Edits the notes of an application in place, saving when the field loses
+/// focus after a change.
+#[component]
+fn NotesInput(id: Uuid, notes: String) -> impl IntoView {
+    let set_notes = ServerAction::<SetNotes>::new();
+    toast_errors(set_notes);
+
+    let on_change = move |ev: web_sys::Event| {
+        set_notes.dispatch(SetNotes {
+            id,
+            notes: event_target_value(&ev),
+        });
+    };
+
+    view! {
+        <textarea class="notes-input" rows="4" placeholder="Notes" on:change=on_change>
+            {notes}
+        </textarea>
+    }
+}
+
+/// Ticks whether the company confirmed receiving an application, saving on
+/// every change.
+#[component]
+fn ConfirmationInput(id: Uuid, received: bool) -> impl IntoView {
+    let set_confirmation = ServerAction::<SetConfirmationReceived>::new();
+    toast_errors(set_confirmation);
+
+    view! {
+        <label class="checkbox-setting">
+            <input
+                type="checkbox"
+                prop:checked=received
+                on:change=move |ev| {
+                    set_confirmation.dispatch(SetConfirmationReceived {
+                        id,
+                        received: event_target_checked(&ev),
+                    });
+                }
+            />
+            "Received"
+        </label>
+    }
+}
+
+/// Edits the deadline of an application in place, saving on every change.
+#[component]
+fn DeadlineInput(id: Uuid, deadline: Option<Date>) -> impl IntoView {
+    let set_deadline = ServerAction::<SetDeadline>::new();
+    toast_errors(set_deadline);
+
+    let on_change = move |ev: web_sys::Event| {
+        let value = event_target_value(&ev);
+        set_deadline.dispatch(SetDeadline {
+            id,
+            deadline: Date::parse(&value, DB_DATE).ok(),
+        });
+    };
+
+    view! {
+        <input
+            type="date"
+            class="deadline-input"
+            value=deadline.and_then(|d| d.format(DB_DATE).ok())
+            on:change=on_change
+        />
+    }
+}
+
+/// The reminders of an application, each editable in place, with a form to
+/// add another.
+#[component]
+fn Reminders(application_id: Uuid) -> impl IntoView {
+    let add = ServerAction::<AddReminder>::new();
+    let update = ServerAction::<UpdateReminder>::new();
+    let set_done = ServerAction::<SetReminderDone>::new();
+    let delete = ServerAction::<DeleteReminder>::new();
+    toast_errors(add);
+    toast_errors(update);
+    toast_errors(set_done);
+    toast_errors(delete);
+    let reminders = Resource::new(
+        move || {
+            (
+                add.version().get(),
+                set_done.version().get(),
+                delete.version().get(),
+            )
+        },
+        move |_| list_reminders(application_id),
+    );
+
+    let remind_at = RwSignal::new(String::new());
+    let text = RwSignal::new(String::new());
+    let on_add = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+        if let Ok(date) = Date::parse(&remind_at.get(), DB_DATE) {
+            add.dispatch(AddReminder {
+                application_id,
+                remind_at: date,
+                text: text.get(),
+            });
+            text.set(String::new());
+        }
+    };
+
+    view! {
+        <h2>"Reminders"</h2>
+        <Transition fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match reminders.await {
+                    Ok(list) => {
+                        view! {
+                            <ul class="reminder-list">
+                                {list
+                                    .into_iter()
+                                    .map(|reminder| {
+                                        let id = reminder.id;
+                                        let date = RwSignal::new(reminder.remind_at);
+                                        let note = RwSignal::new(reminder.text);
+                                        let save = move || {
+                                            update.dispatch(UpdateReminder {
+                                                id,
+                                                remind_at: date.get_untracked(),
+                                                text: note.get_untracked(),
+                                            });
+                                        };
+                                        view! {
+                                            <li class:done=reminder.done>
+                                                <input
+                                                    type="checkbox"
+                                                    title="Done"
+                                                    prop:checked=reminder.done
+                                                    on:change=move |ev| {
+                                                        set_done.dispatch(SetReminderDone {
+                                                            id,
+                                                            done: event_target_checked(&ev),
+                                                        });
+                                                    }
+                                                />
+                                                <input
+                                                    type="date"
+                                                    class="deadline-input"
+                                                    required
+                                                    value=reminder.remind_at.format(DB_DATE).ok()
+                                                    on:change=move |ev| {
+                                                        let value = event_target_value(&ev);
+                                                        if let Ok(d) = Date::parse(&value, DB_DATE) {
+                                                            date.set(d);
+                                                            save();
+                                                        }
+                                                    }
+                                                />
+                                                <input
+                                                    type="text"
+                                                    class="reminder-text"
+                                                    value=note.get_untracked()
+                                                    on:change=move |ev| {
+                                                        note.set(event_target_value(&ev));
+                                                        save();
+                                                    }
+                                                />
+                                                <button
+                                                    class="btn-delete"
+                                                    title="Delete"
+                                                    on:click=move |_| {
+                                                        delete.dispatch(DeleteReminder { id });
+                                                    }
+                                                >
+                                                    "X"
+                                                </button>
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ul>
+                        }
+                            .into_any()
+                    }
+                    Err(e) => view! { <div class="error">{describe_error(&e)}</div> }.into_any(),
+                }
+            })}
+        </Transition>
+        <form class="reminder-form" on:submit=on_add>
+            <input type="date" class="deadline-input" required bind:value=remind_at />
+            <input
+                type="text"
+                class="reminder-text"
+                required
+                placeholder="Ask about the relocation package"
+                bind:value=text
+            />
+            <button type="submit" class="btn-submit" disabled=move || add.pending().get()>
+                "Add reminder"
+            </button>
+        </form>
+    }
+}
+
+#[component]
+fn SimilarApplications(id: Uuid, industry: String) -> impl IntoView {
+    let similar = Resource::new(move || id, get_similar_applications);
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || {
+                let industry = industry.clone();
+                Suspend::new(async move {
+                    let similar = similar.await.unwrap_or_default();
+                    (!similar.is_empty())
+                        .then(|| {
+                            view! {
+                                <h2>"Also applied in " {industry.trim().to_string()}</h2>
+                                <ul class="similar-applications">
+                                    {similar
+                                        .into_iter()
+                                        .map(|application| {
+                                            view! {
+                                                <li>
+                                                    <A href=format!("/applications/{}", application.id)>
+                                                        {application.company.name}
+                                                    </A>
+                                                    <span class=format!(
+                                                        "status-badge {}",
+                                                        application.status.css_class(),
+                                                    )>{application.status.label()}</span>
+                                                </li>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </ul>
+                            }
+                        })
+                })
+            }}
+        </Suspense>
+    }
+}
+
+/// Display preferences, saved as soon as they change.
+#[component]
+fn SettingsPage() -> impl IntoView {
+    let preferences = expect_context::<PreferencesContext>();
+
+    view! {
+        <h1>"Settings"</h1>
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                let initial = preferences.stored.await.unwrap_or_default();
+                view! { <PreferencesForm initial /> }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+fn PreferencesForm(initial: Preferences) -> impl IntoView {
+    let preferences = expect_context::<PreferencesContext>();
+    let current = RwSignal::new(preferences.changed.get_untracked().unwrap_or(initial));
+    let update = move |change: &dyn Fn(&mut Preferences)| {
+        let mut next = current.get_untracked();
+        change(&mut next);
+        current.set(next.clone());
+        preferences.set(next);
+    };
+
+    view! {
+        <div class="report-group settings-form">
+            <div class="form-group">
+                <label for="font-size">"Font size"</label>
+                <select
+                    id="font-size"
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev);
+                        if let Some(size) = FontSize::ALL.into_iter().find(|s| s.class() == value) {
+                            update(&|p| p.font_size = size);
+                        }
+                    }
+                >
+                    {FontSize::ALL
+                        .into_iter()
+                        .map(|size| {
+                            view! {
+                                <option
+                                    value=size.class()
+                                    selected=move || current.read().font_size == size
+                                >
+                                    {size.label()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </div>
+            <div class="form-group">
+                <label for="density">"Row density"</label>
+                <select
+                    id="density"
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev);
+                        if let Some(density) = Density::ALL.into_iter().find(|d| d.class() == value) {
+                            update(&|p| p.density = density);
+                        }
+                    }
+                >
+                    {Density::ALL
+                        .into_iter()
+                        .map(|density| {
+                            view! {
+                                <option
+                                    value=density.class()
+                                    selected=move || current.read().density == density
+                                >
+                                    {density.label()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </div>
+            <div class="form-group">
+                <label for="accepted-offer">"When an offer is accepted"</label>
+                <select
+                    id="accepted-offer"
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev);
+                        if let Some(mode) = AcceptedOfferMode::ALL
+                            .into_iter()
+                            .find(|m| m.value() == value)
+                        {
+                            update(&|p| p.accepted_offer = mode);
+                        }
+                    }
+                >
+                    {AcceptedOfferMode::ALL
+                        .into_iter()
+                        .map(|mode| {
+                            view! {
+                                <option
+                                    value=mode.value()
+                                    selected=move || current.read().accepted_offer == mode
+                                >
+                                    {mode.label()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </div>
+            <div class="form-group">
+                <label for="follow-up-advance">"When a follow-up is done"</label>
+                <select
+                    id="follow-up-advance"
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev);
+                        if let Some(mode) = FollowUpAdvanceMode::ALL
+                            .into_iter()
+                            .find(|m| m.value() == value)
+                        {
+                            update(&|p| p.follow_up_advance = mode);
+                        }
+                    }
+                >
+                    {FollowUpAdvanceMode::ALL
+                        .into_iter()
+                        .map(|mode| {
+                            view! {
+                                <option
+                                    value=mode.value()
+                                    selected=move || current.read().follow_up_advance == mode
+                                >
+                                    {mode.label()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </div>
+            <fieldset
+                class="follow-up-advances"
+                disabled=move || current.read().follow_up_advance == FollowUpAdvanceMode::Off
+            >
+                <legend>"Next status after a follow-up"</legend>
+                {Status::ALL
+                    .into_iter()
+                    .filter(|status| !status.next_steps().is_empty())
+                    .map(|status| {
+                        let id = format!("follow-up-advance-{}", status.as_str());
+                        view! {
+                            <div class="form-group">
+                                <label for=id.clone()>{status.label()}</label>
+                                <select
+                                    id=id
+                                    on:change=move |ev| {
+                                        let to = event_target_value(&ev).parse::<Status>().ok();
+                                        update(
+                                            &|p| {
+                                                p.follow_up_advances.retain(|a| a.from != status);
+                                                if let Some(to) = to {
+                                                    p.follow_up_advances
+                                                        .push(StatusAdvance { from: status, to });
+                                                }
+                                            },
+                                        );
+                                    }
+                                >
+                                    <option
+                                        value=""
+                                        selected=move || {
+                                            current.read().advance_after_follow_up(status).is_none()
+                                        }
+                                    >
+                                        "Keep"
+                                    </option>
+                                    {status
+                                        .next_steps()
+                                        .iter()
+                                        .map(|&to| {
+                                            view! {
+                                                <option
+                                                    value=to.as_str()
+                                                    selected=move || {
+                                                        current.read().advance_after_follow_up(status)
+                                                            == Some(to)
+                                                    }
+                                                >
+                                                    {to.label()}
+                                                </option>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </select>
+                            </div>
+                        }
+                    })
+                    .collect_view()}
+            </fieldset>
+            <div class="form-group">
+                <label for="default-sort">"Default sort"</label>
+                <select
+                    id="default-sort"
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev);
+                        if let Ok(sort) = value.parse::<SortOrder>() {
+                            update(&|p| p.default_view.sort = sort);
+                        }
+                    }
+                >
+                    {SortOrder::ALL
+                        .into_iter()
+                        .map(|sort| {
+                            view! {
+                                <option
+                                    value=sort.as_str()
+                                    selected=move || current.read().default_view.sort == sort
+                                >
+                                    {sort.label()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </div>
+            <div class="form-group">
+                <label for="default-status">"Default status filter"</label>
+                <select
+                    id="default-status"
+                    on:change=move |ev| {
+                        let status = event_target_value(&ev).parse::<Status>().ok();
+                        update(&|p| p.default_view.status = status);
+                    }
+                >
+                    <option value="" selected=move || current.read().default_view.status.is_none()>
+                        "All statuses"
+                    </option>
+                    {Status::ALL
+                        .into_iter()
+                        .map(|status| {
+                            view! {
+                                <option
+                                    value=status.as_str()
+                                    selected=move || {
+                                        current.read().default_view.status == Some(status)
+                                    }
+                                >
+                                    {status.label()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </div>
+            <label class="checkbox-setting">
+                <input
+                    type="checkbox"
+                    prop:checked=move || current.read().pixel_font
+                    on:change=move |ev| {
+                        let checked = event_target_checked(&ev);
+                        update(&|p| p.pixel_font = checked);
+                    }
+                />
+                "Use the pixel font"
+            </label>
+            <label class="checkbox-setting">
+                <input
+                    type="checkbox"
+                    prop:checked=move || current.read().quiet_hours.is_some()
+                    on:change=move |ev| {
+                        let checked = event_target_checked(&ev);
+                        update(&|p| p.quiet_hours = checked.then(QuietHours::default));
+                    }
+                />
+                "Hold reminders and the weekly digest back during quiet hours"
+            </label>
+            <fieldset class="quiet-hours" disabled=move || current.read().quiet_hours.is_none()>
+                <legend>"Quiet hours"</legend>
+                <div class="form-group">
+                    <label for="quiet-hours-start">"From"</label>
+                    <select
+                        id="quiet-hours-start"
+                        on:change=move |ev| {
+                            if let Ok(hour) = event_target_value(&ev).parse::<u8>() {
+                                update(
+                                    &|p| {
+                                        if let Some(quiet) = &mut p.quiet_hours {
+                                            quiet.start = hour;
+                                        }
+                                    },
+                                );
+                            }
+                        }
+                    >
+                        {(0..24u8)
+                            .map(|hour| {
+                                view! {
+                                    <option
+                                        value=hour.to_string()
+                                        selected=move || {
+                                            current.read().quiet_hours.unwrap_or_default().start == hour
+                                        }
+                                    >
+                                        {format!("{hour:02}:00")}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label for="quiet-hours-end">"Until"</label>
+                    <select
+                        id="quiet-hours-end"
+                        on:change=move |ev| {
+                            if let Ok(hour) = event_target_value(&ev).parse::<u8>() {
+                                update(
+                                    &|p| {
+                                        if let Some(quiet) = &mut p.quiet_hours {
+                                            quiet.end = hour;
+                                        }
+                                    },
+                                );
+                            }
+                        }
+                    >
+                        {(0..24u8)
+                            .map(|hour| {
+                                view! {
+                                    <option
+                                        value=hour.to_string()
+                                        selected=move || {
+                                            current.read().quiet_hours.unwrap_or_default().end == hour
+                                        }
+                                    >
+                                        {format!("{hour:02}:00")}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+            </fieldset>
+            <fieldset class="status-checkboxes">
+                <legend>"Counts as applied in the statistics"</legend>
+                {Status::ALL
+                    .into_iter()
+                    .map(|status| {
+                        view! {
+                            <label>
+                                <input
+                                    type="checkbox"
+                                    prop:checked=move || current.read().counts_as_applied(status)
+                                    on:change=move |ev| {
+                                        let checked = event_target_checked(&ev);
+                                        update(
+                                            &|p| {
+                                                p.applied_statuses = Status::ALL
+                                                    .into_iter()
+                                                    .filter(|s| {
+                                                        if *s == status {
+                                                            checked
+                                                        } else {
+                                                            p.counts_as_applied(*s)
+                                                        }
+                                                    })
+                                                    .collect();
+                                            },
+                                        );
+                                    }
+                                />
+                                {status.label()}
+                            </label>
+                        }
+                    })
+                    .collect_view()}
+            </fieldset>
+        </div>
+    }
+}
+
+/// One screen with just what is actionable today, each with buttons to move
+/// it on to its next status.
+#[component]
+fn TodayPage() -> impl IntoView {
+    let update_status = ServerAction::<UpdateApplicationStatus>::new();
+    let withdraw_others = ServerAction::<WithdrawOtherApplications>::new();
+    let complete_follow_up = ServerAction::<CompleteFollowUp>::new();
+    let undo_advance = ServerAction::<UndoFollowUpAdvance>::new();
+    toast_errors(update_status);
+    toast_errors(withdraw_others);
+    toast_errors(complete_follow_up);
+    toast_errors(undo_advance);
+    provide_context(update_status);
+    provide_context(withdraw_others);
+    provide_context(complete_follow_up);
+    provide_context(undo_advance);
+    let today = Resource::new(
+        move || {
+            (
+                update_status.version().get(),
+                withdraw_others.version().get(),
+                complete_follow_up.version().get(),
+                undo_advance.version().get(),
+            )
+        },
+        |_| get_today(),
+    );
+
+    view! {
+        <h1>"Today"</h1>
+        <AcceptedOfferPrompt />
+        <FollowUpAdvancePrompt />
+        <Transition fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match today.await {
+                    Ok(today) if today.is_empty() => {
+                        view! { <p class="empty">"Nothing to do today."</p> }.into_any()
+                    }
+                    Ok(today) => {
+                        view! {
+                            <TodayBucket title="Closing today" applications=today.deadlines />
+                            <TodayBucket title="Follow up" applications=today.follow_ups />
+                            <TodayBucket title="Apply" applications=today.to_do />
+                        }
+                            .into_any()
+                    }
+                    Err(e) => view! { <div class="error">{describe_error(&e)}</div> }.into_any(),
+                }
+            })}
+        </Transition>
+    }
+}
+
+#[component]
+fn TodayBucket(title: &'static str, applications: Vec<AllApplicationsResponse>) -> impl IntoView {
+    let update_status = expect_context::<ServerAction<UpdateApplicationStatus>>();
+    let complete_follow_up = expect_context::<ServerAction<CompleteFollowUp>>();
+
+    (!applications.is_empty()).then(|| {
+        view! {
+            <h2>{title}</h2>
+            <ul class="today-list">
+                {applications
+                    .into_iter()
+                    .map(|application| {
+                        let id = application.id;
+                        view! {
+                            <li>
+                                <A href=format!("/applications/{id}")>{application.company.name}</A>
+                                <span class=format!(
+                                    "status-badge {}",
+                                    application.status.css_class(),
+                                )>{application.status.label()}</span>
+                                {application.follow_up.map(|date| {
+                                    view! {
+                                        <span class="history-date">{format!("Follow up {date}")}</span>
+                                        <button
+                                            class="btn-secondary"
+                                            disabled=move || complete_follow_up.pending().get()
+                                            on:click=move |_| {
+                                                complete_follow_up.dispatch(CompleteFollowUp { id });
+                                            }
+                                        >
+                                            "Done"
+                                        </button>
+                                    }
+                                })}
+                                <span class="today-actions">
+                                    {application
+                                        .status
+                                        .next_steps()
+                                        .iter()
+                                        .map(|&status| {
+                                            view! {
+                                                <button
+                                                    class="btn-secondary"
+                                                    disabled=move || update_status.pending().get()
+                                                    on:click=move |_| {
+                                                        update_status
+                                                            .dispatch(UpdateApplicationStatus {
+                                                                id: id.to_string(),
+                                                                status,
+                                                            });
+                                                    }
+                                                >
+                                                    {status.label()}
+                                                </button>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </span>
+                            </li>
+                        }
+                    })
+                    .collect_view()}
+            </ul>
+        }
+    })
+}
+
+/// Everything that happened to any application, newest first.
+#[component]
+fn TimelinePage() -> impl IntoView {
+    let query = use_query_map();
+    let page = Memo::new(move |_| {
+        query
+            .read()
+            .get("page")
+            .and_then(|p| p.parse::<u32>().ok())
+            .unwrap_or(1)
+            .max(1)
+    });
+    let timeline = Resource::new(
+        move || page.get(),
+        |page| get_activity_timeline(TIMELINE_PAGE_SIZE, page_offset(page, TIMELINE_PAGE_SIZE)),
+    );
+    let href = |page: u32| {
+        if page > 1 {
+            format!("/timeline?page={page}")
+        } else {
+            "/timeline".to_string()
+        }
+    };
+
+    view! {
+        <h1>"Timeline"</h1>
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                let page = page.get();
+                match timeline.await {
+                    Ok(timeline) if timeline.events.is_empty() && page == 1 => {
+                        view! { <p class="empty">"Nothing has happened yet."</p> }.into_any()
+                    }
+                    Ok(timeline) => {
+                        view! {
+                            <ol class="timeline">
+                                {timeline
+                                    .events
+                                    .into_iter()
+                                    .map(|event| {
+                                        view! {
+                                            <li class=event.kind.css_class()>
+                                                <span class="timeline-icon">{event.kind.icon()}</span>
+                                                <span class="history-date">
+                                                    {format!(
+                                                        "{} {:02}:{:02}",
+                                                        event.at.date(),
+                                                        event.at.hour(),
+                                                        event.at.minute(),
+                                                    )}
+                                                </span>
+                                                <A href=format!(
+                                                    "/applications/{}",
+                                                    event.application_id,
+                                                )>{event.company}</A>
+                                                <span>{event.kind.describe(event.status)}</span>
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ol>
+                            <nav class="pager">
+                                {(page > 1).then(|| view! { <A href=href(page - 1)>"◀ Newer"</A> })}
+                                <span>{format!("Page {page}")}</span>
+                                {timeline
+                                    .has_more
+                                    .then(|| view! { <A href=href(page + 1)>"Older ▶"</A> })}
+                            </nav>
+                        }
+                            .into_any()
+                    }
+                    Err(e) => view! { <div class="error">{describe_error(&e)}</div> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+/// Data-hygiene reports that help keep the stored companies tidy.
+#[component]
+fn MaintenancePage() -> impl IntoView {
+    provide_context(ServerAction::<ArchiveAndExport>::new());
+
+    view! {
+        <h1>"Maintenance"</h1>
+        <DuplicateWebsites />
+        <DuplicateApplications />
+        <InactiveCompanies />
+        <CloseTheBooks />
+        <ImportBackup />
+        <PipelineTemplateForm />
+        <AnonymizedExport />
+        <h2>"Attachments"</h2>
+        <div class="report-group archive-form">
+            <p>"Download every attachment in one zip, in a folder per company."</p>
+            <div class="archive-actions">
+                // External, so the router lets the browser fetch the download
+                <a class="btn-secondary" href="/api/attachments.zip" rel="external">
+                    "Download all"
+                </a>
+            </div>
+        </div>
+        <RecomputeDerived />
+    }
+}
+
+#[component]
+fn DuplicateWebsites() -> impl IntoView {
+    let groups = Resource::new(|| (), |_| get_duplicate_websites());
+
+    view! {
+        <h2>"Shared websites"</h2>
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match groups.await {
+                    Ok(groups) if groups.is_empty() => {
+                        view! { <p class="empty">"No companies share a website."</p> }.into_any()
+                    }
+                    Ok(groups) => {
+                        groups
+                            .into_iter()
+                            .map(|group| {
+                                view! {
+                                    <div class="report-group">
+                                        <h3>{group.website}</h3>
+                                        <ul>
+                                            {group
+                                                .companies
+                                                .into_iter()
+                                                .map(|usage| {
+                                                    let search = ApplicationFilter {
+                                                        search: Some(usage.company.name.clone()),
+                                                        ..Default::default()
+                                                    };
+                                                    view! {
+                                                        <li>
+                                                            <A href=format!("/{}", search.to_query_string())>
+                                                                {usage.company.name}
+                                                            </A>
+                                                            <span class="report-count">
+                                                                {format!(
+                                                                    "{} application(s)",
+                                                                    usage.application_count,
+                                                                )}
+                                                            </span>
+                                                        </li>
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </ul>
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                            .into_any()
+                    }
+                    Err(_) => {
+                        view! { <div class="error">"Error loading report"</div> }.into_any()
+                    }
+                }
+            })}
+        </Suspense>
+    }
+}
+
+/// Lists clusters of applications that look entered twice, with the
+/// thresholds to tune what counts as a duplicate. Each application can be
+/// kept, merging the rest of its cluster into it, or deleted on its own.
+#[component]
+fn DuplicateApplications() -> impl IntoView {
+    let merge = ServerAction::<MergeDuplicateApplications>::new();
+    let delete = ServerAction::<DeleteApplication>::new();
+    toast_errors(merge);
+    toast_errors(delete);
+    let thresholds = RwSignal::new(DuplicateThresholds::default());
+    let clusters = Resource::new(
+        move || {
+            (
+                thresholds.get(),
+                merge.version().get(),
+                delete.version().get(),
+            )
+        },
+        |(thresholds, _, _)| find_duplicate_applications(thresholds),
+    );
+
+    view! {
+        <h2>"Duplicate applications"</h2>
+        <div class="archive-actions">
+            <label>
+                "Within days"
+                <input
+                    type="number"
+                    min="0"
+                    prop:value=move || thresholds.read().window_days.to_string()
+                    on:change=move |ev| {
+                        if let Ok(days) = event_target_value(&ev).parse() {
+                            thresholds.update(|t| t.window_days = days);
+                        }
+                    }
+                />
+            </label>
+            <label class="checkbox-setting">
+                <input
+                    type="checkbox"
+                    prop:checked=move || thresholds.read().match_website
+                    on:change=move |ev| {
+                        let checked = event_target_checked(&ev);
+                        thresholds.update(|t| t.match_website = checked);
+                    }
+                />
+                "Companies sharing a website are the same"
+            </label>
+        </div>
+        <Transition fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match clusters.await {
+                    Ok(clusters) if clusters.is_empty() => {
+                        view! { <p class="empty">"No applications look like duplicates."</p> }
+                            .into_any()
+                    }
+                    Ok(clusters) => {
+                        clusters
+                            .into_iter()
+                            .map(|cluster| {
+                                let ids: Vec<Uuid> = cluster
+                                    .applications
+                                    .iter()
+                                    .map(|a| a.id)
+                                    .collect();
+                                view! {
+                                    <div class="report-group">
+                                        <h3>
+                                            {format!(
+                                                "{} ({} applications)",
+                                                cluster.applications[0].company.name,
+                                                cluster.applications.len(),
+                                            )}
+                                        </h3>
+                                        <ul>
+                                            {cluster
+                                                .applications
+                                                .into_iter()
+                                                .map(|application| {
+                                                    let id = application.id;
+                                                    let duplicates: Vec<Uuid> = ids
+                                                        .iter()
+                                                        .copied()
+                                                        .filter(|other| *other != id)
+                                                        .collect();
+                                                    view! {
+                                                        <li>
+                                                            <A href=format!(
+                                                                "/applications/{id}",
+                                                            )>{application.company.name}</A>
+                                                            <span class=format!(
+                                                                "status-badge {}",
+                                                                application.status.css_class(),
+                                                            )>{application.status.label()}</span>
+                                                            <span class="report-count">
+                                                                {application.date.date().to_string()}
+                                                            </span>
+                                                            <button
+                                                                class="btn-secondary"
+                                                                disabled=move || merge.pending().get()
+                                                                on:click=move |_| {
+                                                                    merge
+                                                                        .dispatch(MergeDuplicateApplications {
+                                                                            keep: id,
+                                                                            duplicates: duplicates.clone(),
+                                                                        });
+                                                                }
+                                                            >
+                                                                "Keep, merge others"
+                                                            </button>
+                                                            <button
+                                                                class="btn-delete"
+                                                                disabled=move || delete.pending().get()
+                                                                on:click=move |_| {
+                                                                    delete
+                                                                        .dispatch(DeleteApplication {
+                                                                            id: id.to_string(),
+                                                                        });
+                                                                }
+                                                            >
+                                                                "Delete"
+                                                            </button>
+                                                        </li>
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </ul>
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                            .into_any()
+                    }
+                    Err(e) => view! { <div class="error">{describe_error(&e)}</div> }.into_any(),
+                }
+            })}
+        </Transition>
+    }
+}
+
+/// Lists companies whose applications have all been archived, offering to
+/// remove them for good.
+#[component]
+fn InactiveCompanies() -> impl IntoView {
+    let archive = expect_context::<ServerAction<ArchiveAndExport>>();
+    let remove = ServerAction::<RemoveInactiveCompany>::new();
+    toast_errors(remove);
+    let companies = Resource::new(
+        move || (archive.version().get(), remove.version().get()),
+        |_| get_inactive_companies(),
+    );
+
+    view! {
+        <h2>"Inactive companies"</h2>
+        <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+            {move || Suspend::new(async move {
+                match companies.await {
+                    Ok(companies) if companies.is_empty() => {
+                        view! { <p class="empty">"Every company has an active application."</p> }
+                            .into_any()
+                    }
+                    Ok(companies) => {
+                        view! {
+                            <div class="report-group">
+                                <ul>
+                                    {companies
+                                        .into_iter()
+                                        .map(|usage| {
+                                            let id = usage.company.id;
+                                            view! {
+                                                <li>
+                                                    <span>{usage.company.name}</span>
+                                                    <span class="report-count">
+                                                        {format!(
+                                                            "{} archived application(s)",
+                                                            usage.application_count,
+                                                        )}
+                                                    </span>
+                                                    <button
+                                                        class="btn-delete"
+                                                        disabled=move || remove.pending().get()
+                                                        on:click=move |_| {
+                                                            remove.dispatch(RemoveInactiveCompany { id });
+                                                        }
+                                                    >
+                                                        "Remove"
+                                                    </button>
+                                                </li>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </ul>
+                            </div>
+                        }
+                            .into_any()
+                    }
+                    Err(e) => view! { <div class="error">{describe_error(&e)}</div> }.into_any(),
+                }
+            })}
+        </Suspense>
+    }
+}
+
+/// Downloads every application with private text replaced by placeholders,
+/// to share when reporting a problem.
+#[component]
+fn AnonymizedExport() -> impl IntoView {
+    let export = ServerAction::<ExportAnonymized>::new();
+    toast_errors(export);
+
+    view! {
+        <h2>"Anonymized export"</h2>
+        <div class="report-group archive-form">
+            <p>
+                "Export all applications with company names, contacts, websites, notes and reminders replaced by placeholders. Statuses, dates and history stay as they are, so the export can be shared to reproduce a problem."
+            </p>
+            <div class="archive-actions">
+                <button
+                    class="btn-secondary"
+                    disabled=move || export.pending().get()
+                    on:click=move |_| {
+                        export.dispatch(ExportAnonymized {});
+                    }
+                >
+                    "Export anonymized"
+                </button>
+                {move || {
+                    export
+                        .value()
+                        .get()
+                        .and_then(Result::ok)
+                        .map(|applications| {
+                            let count = applications.len();
+                            let json = serde_json::to_string_pretty(&applications)
+                                .unwrap_or_default();
+                            view! {
+                                <a
+                                    class="btn-secondary"
+                                    download="soulcrush-anonymized.json"
+                                    href=format!(
+                                        "data:application/json;charset=utf-8,{}",
+                                        Url::escape(&json),
+                                    )
+                                >
+                                    "Download"
+                                </a>
+                                <span class="report-count">
+                                    {format!("{count} application(s)")}
+                                </span>
+                            }
+                        })
+                }}
+            </div>
+        </div>
+    }
+}
+
+/// Downloads the setup of this tracker as a template, and applies a pasted
+/// one.
+#[component]
+fn PipelineTemplateForm() -> impl IntoView {
+    let export = ServerAction::<ExportPipelineTemplate>::new();
+    let import = ServerAction::<ImportPipelineTemplate>::new();
+    toast_errors(export);
+    toast_errors(import);
+    let json = RwSignal::new(String::new());
+
+    view! {
+        <h2>"Pipeline template"</h2>
+        <div class="report-group archive-form">
+            <p>
+                "Share how this tracker is set up: preferences, saved filters and industries. No applications or companies are included."
+            </p>
+            <div class="archive-actions">
+                <button
+                    class="btn-secondary"
+                    disabled=move || export.pending().get()
+                    on:click=move |_| {
+                        export.dispatch(ExportPipelineTemplate {});
+                    }
+                >
+                    "Export template"
+                </button>
+                {move || {
+                    export
+                        .value()
+                        .get()
+                        .and_then(Result::ok)
+                        .map(|template| {
+                            let json = serde_json::to_string_pretty(&template).unwrap_or_default();
+                            view! {
+                                <a
+                                    class="btn-secondary"
+                                    download="soulcrush-template.json"
+                                    href=format!(
+                                        "data:application/json;charset=utf-8,{}",
+                                        Url::escape(&json),
+                                    )
+                                >
+                                    "Download template"
+                                </a>
+                            }
+                        })
+                }}
+            </div>
+            <textarea
+                class="import-json"
+                rows="4"
+                placeholder="Paste a template to apply it"
+                bind:value=json
+            />
+            <div class="archive-actions">
+                <button
+                    class="btn-submit"
+                    disabled=move || json.read().trim().is_empty() || import.pending().get()
+                    on:click=move |_| {
+                        import.dispatch(ImportPipelineTemplate { json: json.get() });
+                    }
+                >
+                    "Apply template"
+                </button>
+                {move || {
+                    import
+                        .value()
+                        .get()
+                        .and_then(Result::ok)
+                        .map(|imported| {
+                            format!(
+                                "Added {} filter(s), {} industry(ies) and {} alias(es).",
+                                imported.filter_presets,
+                                imported.industries,
+                                imported.aliases,
+                            )
+                        })
+                }}
+            </div>
+        </div>
+    }
+}
+
+/// Restores applications from a pasted archive export, with a choice of what
+/// to do with the ones that already exist.
+#[component]
+fn ImportBackup() -> impl IntoView {
+    let import = ServerAction::<ImportApplications>::new();
+    toast_errors(import);
+    let json = RwSignal::new(String::new());
+    let conflict_mode = RwSignal::new(ConflictMode::default());
+
+    let on_submit = move |_| {
+        import.dispatch(ImportApplications {
+            json: json.get(),
+            conflict_mode: conflict_mode.get(),
+        });
+    };
+
+    view! {
+        <h2>"Import"</h2>
+        <div class="report-group archive-form">
+            <p>"Paste the JSON of an export to restore its applications."</p>
+            <textarea class="import-json" rows="6" bind:value=json />
+            <div class="archive-actions">
+                <label>
+                    "When it already exists"
+                    <select on:change=move |ev| {
+                        let value = event_target_value(&ev);
+                        if let Some(mode) = ConflictMode::ALL.into_iter().find(|m| m.value() == value)
+                        {
+                            conflict_mode.set(mode);
+                        }
+                    }>
+                        {ConflictMode::ALL
+                            .into_iter()
+                            .map(|mode| {
+                                view! {
+                                    <option
+                                        value=mode.value()
+                                        selected=move || conflict_mode.get() == mode
+                                    >
+                                        {mode.label()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </label>
+                <button
+                    class="btn-submit"
+                    disabled=move || json.read().trim().is_empty() || import.pending().get()
+                    on:click=on_submit
+                >
+                    "Import"
+                </button>
+            </div>
+            {move || {
+                import
+                    .value()
+                    .get()
+                    .and_then(Result::ok)
+                    .map(|outcomes| {
+                        view! {
+                            <p>{format!("Processed {} application(s).", outcomes.len())}</p>
+                            <ul class="import-outcomes">
+                                {outcomes
+                                    .into_iter()
+                                    .map(|outcome| {
+                                        let (href, result) = match outcome.result {
+                                            ImportResult::Created => (Some(outcome.id), "imported"),
+                                            ImportResult::Skipped => (None, "skipped, already exists"),
+                                            ImportResult::Overwritten => (Some(outcome.id), "overwritten"),
+                                            ImportResult::CreatedAs(id) => (Some(id), "imported as a copy"),
+                                        };
+                                        view! {
+                                            <li>
+                                                {match href {
+                                                    Some(id) => {
+                                                        view! {
+                                                            <A href=format!(
+                                                                "/applications/{id}",
+                                                            )>{outcome.company}</A>
+                                                        }
+                                                            .into_any()
+                                                    }
+                                                    None => view! { <span>{outcome.company}</span> }.into_any(),
+                                                }}
+                                                <span class="report-count">{result}</span>
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </ul>
+                        }
+                    })
+            }}
+        </div>
+    }
+}
+
+/// Runs [`recompute_derived`] on demand and shows what it repaired.
+#[component]
+fn RecomputeDerived() -> impl IntoView {
+    let recompute = ServerAction::<RecomputeDerived>::new();
+    toast_errors(recompute);
+
+    view! {
+        <h2>"Consistency"</h2>
+        <div class="report-group archive-form">
+            <p>
+                "Recompute industries, websites, update times and history after editing the database by hand."
+            </p>
+            <button
+                class="btn-secondary"
+                disabled=move || recompute.pending().get()
+                on:click=move |_| {
+                    recompute.dispatch(RecomputeDerived {});
+                }
+            >
+                "Recompute"
+            </button>
+            {move || {
+                recompute
+                    .value()
+                    .get()
+                    .and_then(Result::ok)
+                    .map(|report| {
+                        if report.is_clean() {
+                            return view! { <p class="empty">"Everything was consistent."</p> }
+                                .into_any();
+                        }
+                        let lines = [
+                            ("Industry links updated", report.industries_relinked),
+                            ("Websites normalized", report.websites_normalized),
+                            ("Invalid websites left alone", report.invalid_websites),
+                            ("Update times repaired", report.updated_at_repaired),
+                            ("Missing histories started", report.histories_seeded),
+                            ("Orphan companies removed", report.orphan_companies_removed),
+                        ];
+                        view! {
+                            <ul>
+                                {lines
+                                    .into_iter()
+                                    .filter(|(_, count)| *count > 0)
+                                    .map(|(what, count)| view! { <li>{format!("{what}: {count}")}</li> })
+                                    .collect_view()}
+                            </ul>
+                        }
+                            .into_any()
+                    })
+            }}
+        </div>
+    }
+}
+
+/// Archives finished applications up to a cutoff date and offers them as a
+/// JSON download.
+#[component]
+fn CloseTheBooks() -> impl IntoView {
+    let archive = expect_context::<ServerAction<ArchiveAndExport>>();
+    toast_errors(archive);
+    let statuses = RwSignal::new(HashSet::from([
+        Status::Accepted,
+        Status::Rejected,
+        Status::Withdrawn,
+    ]));
+    let before = RwSignal::new(String::new());
+
+    let on_submit = move |_| {
+        if let Ok(before) = Date::parse(&before.get(), DB_DATE) {
+            archive.dispatch(ArchiveAndExport {
+                statuses: Status::ALL
+                    .into_iter()
+                    .filter(|s| statuses.read().contains(s))
+                    .collect(),
+                before,
+            });
+        }
+    };
+
+    view! {
+        <h2>"Close the books"</h2>
+        <div class="report-group archive-form">
+            <p>
+                "Archive every application with one of these statuses dated before the cutoff, and download them as JSON."
+            </p>
+            <div class="status-checkboxes">
+                {Status::ALL
+                    .into_iter()
+                    .map(|status| {
+                        view! {
+                            <label>
+                                <input
+                                    type="checkbox"
+                                    prop:checked=move || statuses.read().contains(&status)
+                                    on:change=move |ev| {
+                                        let checked = event_target_checked(&ev);
+                                        statuses
+                                            .update(|s| {
+                                                if checked {
+                                                    s.insert(status);
+                                                } else {
+                                                    s.remove(&status);
+                                                }
+                                            });
+                                    }
+                                />
+                                {status.label()}
+                            </label>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+            <div class="archive-actions">
+                <input type="date" title="Archive applications dated before" bind:value=before />
+                <button
+                    class="btn-submit"
+                    disabled=move || {
+                        before.read().is_empty() || statuses.read().is_empty()
+                            || archive.pending().get()
+                    }
+                    on:click=on_submit
+                >
+                    "Archive & export"
+                </button>
+            </div>
+            {move || {
+                archive
+                    .value()
+                    .get()
+                    .and_then(Result::ok)
+                    .map(|export| {
+                        let json = serde_json::to_string_pretty(&export.applications)
+                            .unwrap_or_default();
+                        view! {
+                            <p>{format!("Archived {} application(s).", export.archived)}</p>
+                            <a
+                                class="btn-secondary"
+                                download="soulcrush-archive.json"
+                                href=format!(
+                                    "data:application/json;charset=utf-8,{}",
+                                    Url::escape(&json),
+                                )
+                            >
+                                "Download export"
+                            </a>
+                        }
+                    })
+            }}
+        </div>
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<Application> for AllApplicationsResponse {
+    fn from(s: Application) -> Self {
+        Self {
+            id: s.id,
+            company: s.company,
+            status: s.status,
+            date: s.date,
+            updated_at: s.date,
+            follow_up: None,
+            deadline: s.deadline,
+            completeness: 0,
+            notes: None,
+        }
+        .with_completeness(false)
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(sqlx::FromRow)]
+struct ApplicationRow {
+    id: String,
+    status: String,
+    date: String,
+    updated_at: String,
+    follow_up: Option<String>,
+    deadline: Option<String>,
+    has_notes: bool,
+    company_id: String,
+    name: String,
+    website: String,
+    ceo: String,
+    industry: String,
+}
+
+#[cfg(feature = "ssr")]
+impl TryFrom<ApplicationRow> for AllApplicationsResponse {
+    type Error = ServerFnError;
+
+    fn try_from(r: ApplicationRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Uuid::parse_str(&r.id).map_err(|e| ServerFnError::new(e.to_string()))?,
+            status: r
+                .status
+                .parse()
+                .map_err(|e: String| ServerFnError::new(e))?,
+            date: date_from_db(&r.date).map_err(|e| ServerFnError::new(e.to_string()))?,
+            updated_at: date_from_db(&r.updated_at)
+                .map_err(|e| ServerFnError::new(e.to_string()))?,
+            follow_up: r
+                .follow_up
+                .map(|d| Date::parse(&d, DB_DATE))
+                .transpose()
+                .map_err(|e| ServerFnError::new(e.to_string()))?,
+            deadline: r
+                .deadline
+                .map(|d| Date::parse(&d, DB_DATE))
+                .transpose()
+                .map_err(|e| ServerFnError::new(e.to_string()))?,
+            company: Company {
+                id: Uuid::parse_str(&r.company_id)
+                    .map_err(|e| ServerFnError::new(e.to_string()))?,
+                name: r.name,
+                website: r.website,
+                ceo: r.ceo,
+                industry: r.industry,
+            },
+            completeness: 0,
+            notes: None,
+        }
+        .with_completeness(r.has_notes))
+    }
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct ApplicationPage {
+    applications: Vec<AllApplicationsResponse>,
+    /// The page returned, counting from 1.
+    page: u32,
+    /// How many applications match the filter across all pages.
+    total: u32,
+}
+
+impl ApplicationPage {
+    fn page_count(&self) -> u32 {
+        self.total.div_ceil(PAGE_SIZE).max(1)
+    }
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub(crate) struct AllApplicationsResponse {
+    id: Uuid,
+    company: Company,
+    status: Status,
+    #[serde(with = "time::serde::rfc3339")]
+    date: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    updated_at: OffsetDateTime,
+    follow_up: Option<Date>,
+    /// When the posting closes.
+    deadline: Option<Date>,
+    /// How much of the optional detail is filled in, as a percentage
+    /// weighted by [`COMPLETENESS_WEIGHTS`]. Derived, so ignored on import.
+    #[serde(default)]
+    completeness: u8,
+    /// The notes, only loaded for archive exports so they survive a round
+    /// trip through one. `None` everywhere else, and in exports made before
+    /// they were included.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    notes: Option<String>,
+}
+
+/// The optional details of an application that [`COMPLETENESS_WEIGHTS`]
+/// rewards filling in.
+#[cfg(feature = "ssr")]
+#[derive(Clone, Copy)]
+enum CompletenessField {
+    /// The CEO, the one contact stored per company.
+    Contact,
+    Industry,
+    Notes,
+    Deadline,
+    FollowUp,
+}
+
+/// What each optional detail adds to an application's completeness.
+#[cfg(feature = "ssr")]
+const COMPLETENESS_WEIGHTS: [(CompletenessField, u32); 5] = [
+    (CompletenessField::Contact, 25),
+    (CompletenessField::Industry, 15),
+    (CompletenessField::Notes, 30),
+    (CompletenessField::Deadline, 15),
+    (CompletenessField::FollowUp, 15),
+];
+
+#[cfg(feature = "ssr")]
+impl AllApplicationsResponse {
+    /// Scores which optional details are filled in. Notes are rarely loaded
+    /// into the response, so whether there are any is passed in.
+    fn with_completeness(mut self, has_notes: bool) -> Self {
+        let filled = |field: CompletenessField| match field {
+            CompletenessField::Contact => !self.company.ceo.trim().is_empty(),
+            CompletenessField::Industry => !self.company.industry.trim().is_empty(),
+            CompletenessField::Notes => has_notes,
+            CompletenessField::Deadline => self.deadline.is_some(),
+            CompletenessField::FollowUp => self.follow_up.is_some(),
+        };
+        let total: u32 = COMPLETENESS_WEIGHTS.iter().map(|(_, weight)| weight).sum();
+        let scored: u32 = COMPLETENESS_WEIGHTS
+            .iter()
+            .filter(|(field, _)| filled(*field))
+            .map(|(_, weight)| weight)
+            .sum();
+        self.completeness = (scored * 100 / total) as u8;
+        self
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(sqlx::FromRow)]
+struct StatusHistoryRow {
+    status: String,
+    changed_at: String,
+    reason: Option<String>,
+}
+
+#[cfg(feature = "ssr")]
+impl TryFrom<StatusHistoryRow> for StatusChange {
+    type Error = ServerFnError;
+
+    fn try_from(r: StatusHistoryRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: r
+                .status
+                .parse()
+                .map_err(|e: String| ServerFnError::new(e))?,
+            changed_at: date_from_db(&r.changed_at)
+                .map_err(|e| ServerFnError::new(e.to_string()))?,
+            reason: r.reason,
+        })
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(sqlx::FromRow)]
+struct FilterPresetRow {
+    id: String,
+    name: String,
+    status: Option<String>,
+    search: Option<String>,
+    search_notes: bool,
+    sort: String,
+    date_from: Option<String>,
+    date_to: Option<String>,
+}
+
+#[cfg(feature = "ssr")]
+impl TryFrom<FilterPresetRow> for FilterPreset {
+    type Error = ServerFnError;
+
+    fn try_from(r: FilterPresetRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Uuid::parse_str(&r.id).map_err(|e| ServerFnError::new(e.to_string()))?,
+            name: r.name,
+            filter: ApplicationFilter {
+                status: r
+                    .status
+                    .map(|s| s.parse())
+                    .transpose()
+                    .map_err(|e: String| ServerFnError::new(e))?,
+                search: r.search,
+                search_notes: r.search_notes,
+                sort: r.sort.parse().map_err(|e: String| ServerFnError::new(e))?,
+                from: r.date_from,
+                to: r.date_to,
+            },
+        })
+    }
+}
+
+/// The list view's search, filter and sort criteria, mirrored in the URL query.
+#[derive(Clone, Default, PartialEq, Deserialize, Serialize, Debug)]
+pub(crate) struct ApplicationFilter {
+    status: Option<Status>,
+    search: Option<String>,
+    /// Whether the search also matches notes, besides company and industry.
+    search_notes: bool,
+    sort: SortOrder,
+    /// Inclusive lower bound on the application date, as `YYYY-MM-DD`.
+    from: Option<String>,
+    /// Inclusive upper bound on the application date, as `YYYY-MM-DD`.
+    to: Option<String>,
+}
+
+impl ApplicationFilter {
+    /// This filter without its search text, which tends to name companies.
+    #[cfg(feature = "ssr")]
+    fn without_search(self) -> Self {
+        Self {
+            search: None,
+            search_notes: false,
+            ..self
+        }
+    }
+
+    /// Whether every live application matches, whatever the order.
+    fn matches_everything(&self) -> bool {
+        self.status.is_none() && self.search.is_none() && self.from.is_none() && self.to.is_none()
+    }
+
+    fn from_query(query: &ParamsMap) -> Self {
+        let non_empty = |key| query.get(key).filter(|v: &String| !v.is_empty());
+
+        Self {
+            status: query.get("status").and_then(|s| s.parse().ok()),
+            search: non_empty("q"),
+            search_notes: query.get("notes").is_some_and(|v| v == "1"),
+            sort: query
+                .get("sort")
+                .and_then(|s| s.parse().ok())
+                .unwrap_or_default(),
+            from: non_empty("from"),
+            to: non_empty("to"),
+        }
+    }
+
+    fn to_query_string(&self) -> String {
+        self.to_query().to_query_string()
+    }
+
+    fn to_query(&self) -> ParamsMap {
+        let mut query = ParamsMap::new();
+        if let Some(status) = self.status {
+            query.insert("status", status.as_str().to_string());
+        }
+        if let Some(search) = &self.search {
+            query.insert("q", search.clone());
+        }
+        if self.search_notes {
+            query.insert("notes", "1".to_string());
+        }
+        if self.sort != SortOrder::default() {
+            query.insert("sort", self.sort.as_str().to_string());
+        }
+        if let Some(from) = &self.from {
+            query.insert("from", from.clone());
+        }
+        if let Some(to) = &self.to {
+            query.insert("to", to.clone());
+        }
+        query
+    }
+}
+
+/// An application as [`export_anonymized`] writes it. It carries the fields
+/// of an archive export, so it imports as one.
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct AnonymizedApplication {
+    #[serde(flatten)]
+    application: AllApplicationsResponse,
+    notes: String,
+    history: Vec<StatusChange>,
+    reminders: Vec<Reminder>,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(sqlx::FromRow)]
+struct ApplicationHistoryRow {
+    application_id: String,
+    #[sqlx(flatten)]
+    change: StatusHistoryRow,
+}
+
+/// How a tracker is set up, without any of its data. See
+/// [`export_pipeline_template`].
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct PipelineTemplate {
+    version: u32,
+    preferences: Preferences,
+    filter_presets: Vec<PresetTemplate>,
+    industries: Vec<IndustryTemplate>,
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct PresetTemplate {
+    name: String,
+    filter: ApplicationFilter,
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct IndustryTemplate {
+    id: String,
+    name: String,
+    aliases: Vec<String>,
+}
+
+/// What [`import_pipeline_template`] added.
+#[derive(Clone, Default, PartialEq, Deserialize, Serialize, Debug)]
+struct TemplateImport {
+    filter_presets: u64,
+    industries: u64,
+    aliases: u64,
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct FilterPreset {
+    id: Uuid,
+    name: String,
+    filter: ApplicationFilter,
+}
+
+/// Per-user settings, stored as one JSON document.
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+#[serde(default)]
+pub(crate) struct Preferences {
+    font_size: FontSize,
+    density: Density,
+    /// Whether to use the retro pixel fonts rather than a plain sans-serif.
+    pixel_font: bool,
+    /// The statuses that count as actually applied in the statistics, in
+    /// [`Status::ALL`] order.
+    applied_statuses: Vec<Status>,
+    accepted_offer: AcceptedOfferMode,
+    follow_up_advance: FollowUpAdvanceMode,
+    /// The status each status moves on to once its follow-up is done.
+    follow_up_advances: Vec<StatusAdvance>,
+    /// The list view the home page opens with when the URL has none.
+    default_view: ApplicationFilter,
+    /// When reminders and the weekly digest wait, or `None` to let them out
+    /// as soon as they are due.
+    quiet_hours: Option<QuietHours>,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            font_size: FontSize::default(),
+            density: Density::default(),
+            pixel_font: true,
+            applied_statuses: Status::ALL
+                .into_iter()
+                .filter(|s| *s != Status::ToDo)
+                .collect(),
+            accepted_offer: AcceptedOfferMode::default(),
+            follow_up_advance: FollowUpAdvanceMode::default(),
+            follow_up_advances: vec![StatusAdvance {
+                from: Status::Solicitated,
+                to: Status::Pending,
+            }],
+            default_view: ApplicationFilter::default(),
+            quiet_hours: None,
+        }
+    }
+}
+
+impl Preferences {
+    #[cfg(feature = "ssr")]
+    pub(crate) fn quiet_hours(&self) -> Option<QuietHours> {
+        self.quiet_hours
+    }
+
+    fn counts_as_applied(&self, status: Status) -> bool {
+        self.applied_statuses.contains(&status)
+    }
+
+    /// How many of the applications counted in `by_status` count as applied,
+    /// and how many of those got an answer.
+    fn applied_and_responded(&self, by_status: &[(Status, i64)]) -> (i64, i64) {
+        let applied = by_status
+            .iter()
+            .filter(|(status, _)| self.counts_as_applied(*status));
+        (
+            applied.clone().map(|(_, count)| count).sum(),
+            applied
+                .filter(|(status, _)| status.is_response())
+                .map(|(_, count)| count)
+                .sum(),
+        )
+    }
+
+    fn advance_after_follow_up(&self, status: Status) -> Option<Status> {
+        self.follow_up_advances
+            .iter()
+            .find(|advance| advance.from == status)
+            .map(|advance| advance.to)
+    }
+
+    /// Classes for the `<html>` element that apply these preferences.
+    fn root_class(&self) -> String {
+        let mut classes = vec![self.font_size.class(), self.density.class()];
+        if !self.pixel_font {
+            classes.push("plain-font");
+        }
+        classes.join(" ")
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Deserialize, Serialize, Debug)]
+enum FontSize {
+    Small,
+    #[default]
+    Normal,
+    Large,
+    ExtraLarge,
+}
+
+impl FontSize {
+    const ALL: [FontSize; 4] = [
+        FontSize::Small,
+        FontSize::Normal,
+        FontSize::Large,
+        FontSize::ExtraLarge,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            FontSize::Small => "Small",
+            FontSize::Normal => "Normal",
+            FontSize::Large => "Large",
+            FontSize::ExtraLarge => "Extra large",
+        }
+    }
+
+    fn class(&self) -> &'static str {
+        match self {
+            FontSize::Small => "font-small",
+            FontSize::Normal => "font-normal",
+            FontSize::Large => "font-large",
+            FontSize::ExtraLarge => "font-xlarge",
+        }
+    }
+}
+
+/// How much vertical room each row of the list gets.
+#[derive(Default, Clone, Copy, PartialEq, Deserialize, Serialize, Debug)]
+enum Density {
+    Compact,
+    #[default]
+    Normal,
+    Comfortable,
+}
+
+impl Density {
+    const ALL: [Density; 3] = [Density::Compact, Density::Normal, Density::Comfortable];
+
+    fn label(&self) -> &'static str {
+        match self {
+            Density::Compact => "Compact",
+            Density::Normal => "Normal",
+            Density::Comfortable => "Comfortable",
+        }
+    }
+
+    fn class(&self) -> &'static str {
+        match self {
+            Density::Compact => "density-compact",
+            Density::Normal => "density-normal",
+            Density::Comfortable => "density-comfortable",
+        }
+    }
+}
+
+/// What happens to the other active applications once an offer is accepted.
+#[derive(Default, Clone, Copy, PartialEq, Deserialize, Serialize, Debug)]
+enum AcceptedOfferMode {
+    #[default]
+    Off,
+    /// Offer to withdraw them, and only do so once confirmed.
+    Ask,
+    /// Withdraw them in the same transaction as the acceptance.
+    Auto,
+}
+
+impl AcceptedOfferMode {
+    const ALL: [AcceptedOfferMode; 3] = [
+        AcceptedOfferMode::Off,
+        AcceptedOfferMode::Ask,
+        AcceptedOfferMode::Auto,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            AcceptedOfferMode::Off => "Leave the others alone",
+            AcceptedOfferMode::Ask => "Ask to withdraw the others",
+            AcceptedOfferMode::Auto => "Withdraw the others",
+        }
+    }
+
+    fn value(&self) -> &'static str {
+        match self {
+            AcceptedOfferMode::Off => "off",
+            AcceptedOfferMode::Ask => "ask",
+            AcceptedOfferMode::Auto => "auto",
+        }
+    }
+}
+
+/// What happens to an application's status once its follow-up is done.
+#[derive(Default, Clone, Copy, PartialEq, Deserialize, Serialize, Debug)]
+enum FollowUpAdvanceMode {
+    #[default]
+    Off,
+    /// Offer to move it on, and only do so once confirmed.
+    Suggest,
+    /// Move it on in the same transaction, with a way to undo it.
+    Auto,
+}
+
+impl FollowUpAdvanceMode {
+    const ALL: [FollowUpAdvanceMode; 3] = [
+        FollowUpAdvanceMode::Off,
+        FollowUpAdvanceMode::Suggest,
+        FollowUpAdvanceMode::Auto,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            FollowUpAdvanceMode::Off => "Keep the status",
+            FollowUpAdvanceMode::Suggest => "Suggest the next status",
+            FollowUpAdvanceMode::Auto => "Move to the next status",
+        }
+    }
+
+    fn value(&self) -> &'static str {
+        match self {
+            FollowUpAdvanceMode::Off => "off",
+            FollowUpAdvanceMode::Suggest => "suggest",
+            FollowUpAdvanceMode::Auto => "auto",
+        }
+    }
+}
+
+/// A daily window, in the browser's local time, from the `start` hour up to
+/// the `end` hour. It runs past midnight when `end` comes first.
+#[derive(Clone, Copy, PartialEq, Deserialize, Serialize, Debug)]
+pub(crate) struct QuietHours {
+    start: u8,
+    end: u8,
+    /// The browser's offset from UTC when the hours were saved, which lets
+    /// the server tell when they are. Hours saved before it was recorded are
+    /// taken as UTC.
+    #[serde(default)]
+    utc_offset_minutes: i16,
+}
+
+impl QuietHours {
+    fn contains(self, hour: u8) -> bool {
+        if self.start <= self.end {
+            (self.start..self.end).contains(&hour)
+        } else {
+            hour >= self.start || hour < self.end
+        }
+    }
+
+    /// The earliest moment from `now` on that lies outside the window: `now`
+    /// itself, or the end of the quiet hours it falls in.
+    #[cfg(feature = "ssr")]
+    pub(crate) fn next_allowed(self, now: OffsetDateTime) -> OffsetDateTime {
+        let offset = time::UtcOffset::from_whole_seconds(i32::from(self.utc_offset_minutes) * 60)
+            .unwrap_or(time::UtcOffset::UTC);
+        let local = now.to_offset(offset);
+        if !self.contains(local.hour()) {
+            return now;
+        }
+
+        let end = local
+            .replace_time(time::Time::from_hms(self.end, 0, 0).unwrap_or(time::Time::MIDNIGHT));
+        let end = if end <= local {
+            end + time::Duration::days(1)
+        } else {
+            end
+        };
+        end.to_offset(time::UtcOffset::UTC)
+    }
+}
+
+/// The window offered when quiet hours are first turned on.
+impl Default for QuietHours {
+    fn default() -> Self {
+        Self {
+            start: 22,
+            end: 8,
+            utc_offset_minutes: 0,
+        }
+    }
+}
+
+/// One entry of [`Preferences::follow_up_advances`].
+#[derive(Clone, Copy, PartialEq, Deserialize, Serialize, Debug)]
+struct StatusAdvance {
+    from: Status,
+    to: Status,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Deserialize, Serialize, Debug)]
+pub(crate) enum SortOrder {
+    #[default]
+    Newest,
+    Oldest,
+    Company,
+    Status,
+}
+
+impl std::str::FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Newest" => Ok(SortOrder::Newest),
+            "Oldest" => Ok(SortOrder::Oldest),
+            "Company" => Ok(SortOrder::Company),
+            "Status" => Ok(SortOrder::Status),
+            _ => Err(format!("Invalid sort order: {s}")),
+        }
+    }
+}
+
+impl SortOrder {
+    const ALL: [SortOrder; 4] = [
+        SortOrder::Newest,
+        SortOrder::Oldest,
+        SortOrder::Company,
+        SortOrder::Status,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Newest => "Newest",
+            SortOrder::Oldest => "Oldest",
+            SortOrder::Company => "Company",
+            SortOrder::Status => "Status",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            SortOrder::Newest => "Newest first",
+            SortOrder::Oldest => "Oldest first",
+            SortOrder::Company => "Company A-Z",
+            SortOrder::Status => "By status",
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    fn order_by(&self) -> &'static str {
+        match self {
+            SortOrder::Newest => "a.date DESC",
+            SortOrder::Oldest => "a.date ASC",
+            SortOrder::Company => "c.name COLLATE NOCASE ASC, a.date DESC",
+            SortOrder::Status => {
+                "CASE a.status WHEN 'ToDo' THEN 0 WHEN 'Solicitated' THEN 1 \
+                 WHEN 'Pending' THEN 2 WHEN 'Accepted' THEN 3 WHEN 'Rejected' THEN 4 \
+                 ELSE 5 END, a.date DESC"
+            }
+        }
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Deserialize, Serialize, Debug)]
+struct TodayResponse {
+    /// To-do applications whose posting closes today.
+    deadlines: Vec<AllApplicationsResponse>,
+    /// Applications with a follow-up due today or overdue.
+    follow_ups: Vec<AllApplicationsResponse>,
+    /// Every other to-do application, waiting to be sent.
+    to_do: Vec<AllApplicationsResponse>,
+}
+
+impl TodayResponse {
+    fn is_empty(&self) -> bool {
+        self.deadlines.is_empty() && self.follow_ups.is_empty() && self.to_do.is_empty()
+    }
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct ArchiveExport {
+    archived: usize,
+    applications: Vec<AllApplicationsResponse>,
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct NextAction {
+    application: AllApplicationsResponse,
+    /// Why this application was picked, e.g. "Follow-up overdue by 3 days".
+    reason: String,
+}
+
+/// What [`recompute_derived`] repaired, per kind of derived field.
+#[derive(Clone, Default, PartialEq, Deserialize, Serialize, Debug)]
+struct RecomputeReport {
+    industries_relinked: u64,
+    websites_normalized: u64,
+    /// Websites that could not be normalized, and so were not touched.
+    invalid_websites: u64,
+    updated_at_repaired: u64,
+    histories_seeded: u64,
+    orphan_companies_removed: u64,
+}
+
+impl RecomputeReport {
+    fn is_clean(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// What [`import_applications`] does with a record whose id already exists.
+#[derive(Default, Clone, Copy, PartialEq, Deserialize, Serialize, Debug)]
+pub(crate) enum ConflictMode {
+    /// Keep the existing application and drop the record.
+    #[default]
+    Skip,
+    /// Replace the existing application with the record.
+    Overwrite,
+    /// Keep both, importing the record under a new id.
+    CreateNew,
+}
+
+impl ConflictMode {
+    const ALL: [ConflictMode; 3] = [
+        ConflictMode::Skip,
+        ConflictMode::Overwrite,
+        ConflictMode::CreateNew,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            ConflictMode::Skip => "Keep the existing one",
+            ConflictMode::Overwrite => "Overwrite the existing one",
+            ConflictMode::CreateNew => "Import a copy",
+        }
+    }
+
+    fn value(&self) -> &'static str {
+        match self {
+            ConflictMode::Skip => "skip",
+            ConflictMode::Overwrite => "overwrite",
+            ConflictMode::CreateNew => "create-new",
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+pub(crate) enum ImportResult {
+    Created,
+    Skipped,
+    Overwritten,
+    /// Imported next to an existing application with the same id.
+    CreatedAs(Uuid),
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+pub(crate) struct ImportOutcome {
+    /// The id in the imported file.
+    id: Uuid,
+    company: String,
+    result: ImportResult,
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct ApplicationDetailResponse {
+    application: AllApplicationsResponse,
+    history: Vec<StatusChange>,
+    notes: String,
+    /// Whether the company confirmed receiving the application, once sent.
+    confirmation_received: Option<bool>,
+    /// The first page of its attachments, so the gallery opens without
+    /// another round trip.
+    attachments: AttachmentPage,
+}
+
+/// What a status update changed, so the list can apply it without
+/// refetching.
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct StatusChangeEvent {
+    id: Uuid,
+    previous: Status,
+    status: Status,
+    #[serde(with = "time::serde::rfc3339")]
+    updated_at: OffsetDateTime,
+    /// Other applications withdrawn along with accepting this one.
+    withdrawn: u32,
+    /// Other applications that could be withdrawn now that this one is
+    /// accepted, when the preferences ask first.
+    withdrawable: u32,
+}
+
+/// What completing a follow-up did, so the page can offer to apply a
+/// suggested status or undo an applied one.
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct FollowUpCompletion {
+    id: Uuid,
+    company: String,
+    /// The status the application had when its follow-up was done.
+    previous: Status,
+    /// The follow-up date that was cleared.
+    follow_up: Date,
+    /// The status the preferences map `previous` onto, if any.
+    advance: Option<Status>,
+    /// Whether `advance` was applied rather than only suggested.
+    applied: bool,
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct StatusChange {
+    status: Status,
+    #[serde(with = "time::serde::rfc3339")]
+    changed_at: OffsetDateTime,
+    /// Set when the app changed the status on the user's behalf.
+    reason: Option<String>,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(sqlx::FromRow)]
+struct ActivityRow {
+    kind: String,
+    application_id: String,
+    company: String,
+    status: String,
+    at: String,
+}
+
+#[cfg(feature = "ssr")]
+impl TryFrom<ActivityRow> for ActivityEvent {
+    type Error = ServerFnError;
+
+    fn try_from(r: ActivityRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            kind: match r.kind.as_str() {
+                "Created" => ActivityKind::Created,
+                "StatusChanged" => ActivityKind::StatusChanged,
+                "NotesEdited" => ActivityKind::NotesEdited,
+                "Archived" => ActivityKind::Archived,
+                kind => return Err(ServerFnError::new(format!("Invalid activity kind: {kind}"))),
+            },
+            application_id: Uuid::parse_str(&r.application_id)
+                .map_err(|e| ServerFnError::new(e.to_string()))?,
+            company: r.company,
+            status: r
+                .status
+                .parse()
+                .map_err(|e: String| ServerFnError::new(e))?,
+            at: date_from_db(&r.at).map_err(|e| ServerFnError::new(e.to_string()))?,
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct ActivityPage {
+    events: Vec<ActivityEvent>,
+    /// Whether older events exist beyond this page.
+    has_more: bool,
+}
 
-    view! {
-        <div class="create-form-container">
-            <button class="form-toggle" class:closed=move || !is_open.get() on:click=move |_| is_open.update(|v| *v = !*v)>
-                <span class="toggle-icon" class:open=is_open>
-                    "▶"
-                </span>
-                "New Application"
-            </button>
-            <Show when=move || is_open.get()>
-                <MultiActionForm action=create_action attr:class="create-form">
-                    <div class="form-row">
-                        <div class="form-group">
-                            <label for="req[company][name]">"Company Name"</label>
-                            <input type="text" name="req[company][name]" required />
-                        </div>
-                        <div class="form-group">
-                            <label for="req[company][website]">"Website"</label>
-                            <input type="url" name="req[company][website]" required />
-                        </div>
-                    </div>
+/// Something that happened to an application, for the timeline.
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct ActivityEvent {
+    kind: ActivityKind,
+    application_id: Uuid,
+    company: String,
+    /// The status the application was created with or moved to, or had
+    /// when it was archived.
+    status: Status,
+    #[serde(with = "time::serde::rfc3339")]
+    at: OffsetDateTime,
+}
 
-                    <div class="form-row">
-                        <div class="form-group">
-                            <label for="req[company][ceo]">"CEO"</label>
-                            <input type="text" name="req[company][ceo]" required />
-                        </div>
-                        <div class="form-group">
-                            <label for="req[company][industry]">"Industry"</label>
-                            <input type="text" name="req[company][industry]" required />
-                        </div>
-                    </div>
+#[derive(Clone, Copy, PartialEq, Deserialize, Serialize, Debug)]
+enum ActivityKind {
+    Created,
+    StatusChanged,
+    NotesEdited,
+    Archived,
+}
 
-                    <div class="form-row form-actions">
-                        <div class="form-group">
-                            <label for="req[status]">"Status"</label>
-                            <select name="req[status]">
-                                <option value="ToDo">"To Do"</option>
-                                <option value="Solicitated">"Applied"</option>
-                                <option value="Pending">"Pending"</option>
-                                <option value="Accepted">"Accepted"</option>
-                                <option value="Rejected">"Rejected"</option>
-                            </select>
-                        </div>
-                        <button type="submit" class="btn-submit">
-                            "Add Application"
-                        </button>
-                    </div>
-                </MultiActionForm>
-            </Show>
-        </div>
+impl ActivityKind {
+    fn icon(&self) -> &'static str {
+        match self {
+            ActivityKind::Created => "✚",
+            ActivityKind::StatusChanged => "➜",
+            ActivityKind::NotesEdited => "✎",
+            ActivityKind::Archived => "▣",
+        }
+    }
+
+    fn css_class(&self) -> &'static str {
+        match self {
+            ActivityKind::Created => "activity-created",
+            ActivityKind::StatusChanged => "activity-status",
+            ActivityKind::NotesEdited => "activity-notes",
+            ActivityKind::Archived => "activity-archived",
+        }
+    }
+
+    fn describe(&self, status: Status) -> String {
+        match self {
+            ActivityKind::Created => format!("added as {}", status.label()),
+            ActivityKind::StatusChanged => format!("moved to {}", status.label()),
+            ActivityKind::NotesEdited => "notes edited".to_string(),
+            ActivityKind::Archived => "archived".to_string(),
+        }
     }
 }
 
 #[cfg(feature = "ssr")]
-impl From<Application> for AllApplicationsResponse {
-    fn from(s: Application) -> Self {
-        Self {
-            id: s.id,
-            company: s.company,
-            status: s.status,
-            date: s.date.to_string(),
-        }
+#[derive(sqlx::FromRow)]
+struct AttachmentRow {
+    id: String,
+    file_name: String,
+    content_type: String,
+    size: i64,
+    uploaded_at: String,
+}
+
+#[cfg(feature = "ssr")]
+impl TryFrom<AttachmentRow> for Attachment {
+    type Error = ServerFnError;
+
+    fn try_from(r: AttachmentRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Uuid::parse_str(&r.id).map_err(|e| ServerFnError::new(e.to_string()))?,
+            file_name: r.file_name,
+            content_type: r.content_type,
+            size: r.size,
+            uploaded_at: date_from_db(&r.uploaded_at)
+                .map_err(|e| ServerFnError::new(e.to_string()))?,
+        })
     }
 }
 
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct Reminder {
+    id: Uuid,
+    application_id: Uuid,
+    /// The day the reminder falls due.
+    remind_at: Date,
+    text: String,
+    done: bool,
+}
+
 #[cfg(feature = "ssr")]
 #[derive(sqlx::FromRow)]
-struct ApplicationRow {
+struct ReminderRow {
     id: String,
-    status: String,
-    date: String,
-    company_id: String,
-    name: String,
-    website: String,
-    ceo: String,
-    industry: String,
+    application_id: String,
+    remind_at: String,
+    text: String,
+    done: bool,
 }
 
 #[cfg(feature = "ssr")]
-impl TryFrom<ApplicationRow> for AllApplicationsResponse {
+impl TryFrom<ReminderRow> for Reminder {
     type Error = ServerFnError;
 
-    fn try_from(r: ApplicationRow) -> Result<Self, Self::Error> {
+    fn try_from(r: ReminderRow) -> Result<Self, Self::Error> {
         Ok(Self {
             id: Uuid::parse_str(&r.id).map_err(|e| ServerFnError::new(e.to_string()))?,
-            status: r
-                .status
-                .parse()
-                .map_err(|e: String| ServerFnError::new(e))?,
-            date: r.date,
-            company: Company {
-                id: Uuid::parse_str(&r.company_id)
-                    .map_err(|e| ServerFnError::new(e.to_string()))?,
-                name: r.name,
-                website: r.website,
-                ceo: r.ceo,
-                industry: r.industry,
-            },
+            application_id: Uuid::parse_str(&r.application_id)
+                .map_err(|e| ServerFnError::new(e.to_string()))?,
+            remind_at: Date::parse(&r.remind_at, DB_DATE)?,
+            text: r.text,
+            done: r.done,
         })
     }
 }
 
+/// A reminder that fell due, with the company of its application.
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct DueReminder {
+    reminder: Reminder,
+    company: String,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(sqlx::FromRow)]
+struct DueReminderRow {
+    #[sqlx(flatten)]
+    reminder: ReminderRow,
+    company: String,
+}
+
 #[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
-struct AllApplicationsResponse {
+struct AttachmentPage {
+    attachments: Vec<Attachment>,
+    /// How many attachments the application has across all pages.
+    total: u32,
+    /// Bytes taken up by the attachments of every application.
+    used_bytes: i64,
+    quota_bytes: i64,
+}
+
+/// A file attached to an application, without its content.
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct Attachment {
     id: Uuid,
-    company: Company,
+    file_name: String,
+    content_type: String,
+    size: i64,
+    #[serde(with = "time::serde::rfc3339")]
+    uploaded_at: OffsetDateTime,
+}
+
+/// How an attachment can be shown without downloading it, judged by its
+/// content type.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum AttachmentPreview {
+    Image,
+    Pdf,
+    Download,
+}
+
+impl AttachmentPreview {
+    pub(crate) fn of(content_type: &str) -> Self {
+        match content_type {
+            "image/png" | "image/jpeg" | "image/gif" | "image/webp" => AttachmentPreview::Image,
+            "application/pdf" => AttachmentPreview::Pdf,
+            _ => AttachmentPreview::Download,
+        }
+    }
+}
+
+/// The part of an application a share link reveals.
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+pub(crate) struct SharedApplication {
+    company: String,
+    industry: String,
+    website: String,
     status: Status,
-    date: String,
+    #[serde(with = "time::serde::rfc3339")]
+    date: OffsetDateTime,
 }
 
 #[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
 struct CreateApplicationRequest {
     company: CreateCompanyRequest,
     status: Status,
+    #[serde(default, deserialize_with = "optional_form_date")]
+    deadline: Option<Date>,
+}
+
+/// Reads an optional date from a form field, where a date input left blank
+/// submits an empty string rather than nothing.
+fn optional_form_date<'de, D>(deserializer: D) -> Result<Option<Date>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .filter(|s| !s.is_empty())
+        .map(|s| Date::parse(&s, DB_DATE).map_err(serde::de::Error::custom))
+        .transpose()
 }
 
 #[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
@@ -393,25 +8553,273 @@ struct Application {
     company: Company,
     status: Status,
     date: OffsetDateTime,
+    deadline: Option<Date>,
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub(crate) struct Company {
+    id: Uuid,
+    name: String,
+    website: String,
+    ceo: String,
+    industry: String,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(sqlx::FromRow)]
+struct CompanyRow {
+    id: String,
+    name: String,
+    website: String,
+    ceo: String,
+    industry: String,
+}
+
+#[cfg(feature = "ssr")]
+impl TryFrom<CompanyRow> for Company {
+    type Error = ServerFnError;
+
+    fn try_from(r: CompanyRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Uuid::parse_str(&r.id).map_err(|e| ServerFnError::new(e.to_string()))?,
+            name: r.name,
+            website: r.website,
+            ceo: r.ceo,
+            industry: r.industry,
+        })
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(sqlx::FromRow)]
+struct CompanyUsageRow {
+    id: String,
+    name: String,
+    website: String,
+    ceo: String,
+    industry: String,
+    application_count: i64,
+}
+
+#[cfg(feature = "ssr")]
+impl TryFrom<CompanyUsageRow> for CompanyUsage {
+    type Error = ServerFnError;
+
+    fn try_from(r: CompanyUsageRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            company: Company {
+                id: Uuid::parse_str(&r.id).map_err(|e| ServerFnError::new(e.to_string()))?,
+                name: r.name,
+                website: r.website,
+                ceo: r.ceo,
+                industry: r.industry,
+            },
+            application_count: r.application_count,
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct CompanyUsage {
+    company: Company,
+    application_count: i64,
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct DuplicateWebsiteGroup {
+    /// The normalized host the companies share.
+    website: String,
+    companies: Vec<CompanyUsage>,
+}
+
+/// How alike applications must be for [`find_duplicate_applications`] to
+/// cluster them.
+#[derive(Clone, Copy, PartialEq, Deserialize, Serialize, Debug)]
+struct DuplicateThresholds {
+    /// The most days between an application and the next one to the same
+    /// company for both to be in one cluster.
+    window_days: u32,
+    /// Whether companies sharing a website count as the same company.
+    match_website: bool,
+}
+
+impl Default for DuplicateThresholds {
+    fn default() -> Self {
+        Self {
+            window_days: 14,
+            match_website: true,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct DuplicateCluster {
+    /// Oldest first.
+    applications: Vec<AllApplicationsResponse>,
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct DashboardStats {
+    /// Every status, in [`Status::ALL`] order, with zero counts included.
+    by_status: Vec<(Status, i64)>,
+    /// Largest first, with unmatched industries grouped under "Other".
+    by_industry: Vec<IndustryCount>,
+}
+
+/// Weeks averaged on each side of the comparison in [`get_velocity_trend`].
+const VELOCITY_WINDOW_WEEKS: i64 = 4;
+
+/// Changes in the weekly rate smaller than this, in percent, count as flat.
+const FLAT_TREND_PERCENT: f64 = 10.0;
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct VelocityTrend {
+    /// Mean applications sent per week over the recent window.
+    recent_per_week: f64,
+    /// Mean applications sent per week over the window before it.
+    prior_per_week: f64,
+    /// How much the weekly rate changed, or `None` while the history is too
+    /// short to tell.
+    change_percent: Option<f64>,
+}
+
+impl VelocityTrend {
+    fn direction(&self) -> Option<TrendDirection> {
+        self.change_percent.map(|change| {
+            if change.abs() < FLAT_TREND_PERCENT {
+                TrendDirection::Flat
+            } else if change > 0.0 {
+                TrendDirection::Up
+            } else {
+                TrendDirection::Down
+            }
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum TrendDirection {
+    Up,
+    Down,
+    Flat,
+}
+
+impl TrendDirection {
+    fn arrow(self) -> &'static str {
+        match self {
+            TrendDirection::Up => "▲",
+            TrendDirection::Down => "▼",
+            TrendDirection::Flat => "▶",
+        }
+    }
+
+    fn css_class(self) -> &'static str {
+        match self {
+            TrendDirection::Up => "trend up",
+            TrendDirection::Down => "trend down",
+            TrendDirection::Flat => "trend flat",
+        }
+    }
+}
+
+/// How long ago an application was sent, in whole days.
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Debug)]
+enum AgeBucket {
+    Week,
+    Month,
+    Quarter,
+    Older,
+}
+
+impl AgeBucket {
+    #[cfg(feature = "ssr")]
+    const ALL: [AgeBucket; 4] = [
+        AgeBucket::Week,
+        AgeBucket::Month,
+        AgeBucket::Quarter,
+        AgeBucket::Older,
+    ];
+
+    /// The inclusive range of ages in days, open-ended for the oldest and for
+    /// the newest, which also takes applications dated in the future.
+    const fn days(self) -> (Option<i64>, Option<i64>) {
+        match self {
+            AgeBucket::Week => (None, Some(7)),
+            AgeBucket::Month => (Some(8), Some(30)),
+            AgeBucket::Quarter => (Some(31), Some(90)),
+            AgeBucket::Older => (Some(91), None),
+        }
+    }
+
+    const fn label(self) -> &'static str {
+        match self {
+            AgeBucket::Week => "0–7 days",
+            AgeBucket::Month => "8–30 days",
+            AgeBucket::Quarter => "31–90 days",
+            AgeBucket::Older => "91+ days",
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    fn contains(self, days: i64) -> bool {
+        let (min, max) = self.days();
+        min.is_none_or(|min| days >= min) && max.is_none_or(|max| days <= max)
+    }
+
+    /// The application dates that fall in this bucket as of `today`, for the
+    /// list's `from` and `to` filter.
+    fn date_range(self, today: Date) -> (Option<Date>, Option<Date>) {
+        let (min, max) = self.days();
+        (
+            max.map(|max| today - time::Duration::days(max)),
+            min.map(|min| today - time::Duration::days(min)),
+        )
+    }
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct AgeCount {
+    bucket: AgeBucket,
+    count: i64,
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+struct CompanyResponseTime {
+    company: String,
+    /// Mean days from applying to the first response.
+    average_days: f64,
+    /// Applications that got a response.
+    responses: u32,
+    /// Applications sent that have not been answered yet.
+    awaiting: u32,
+}
+
+/// Column the response-time table is sorted by.
+#[derive(Clone, Copy, PartialEq)]
+enum ResponseTimeSort {
+    Company,
+    Fastest,
+    Responses,
 }
 
 #[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
-struct Company {
-    id: Uuid,
-    name: String,
-    website: String,
-    ceo: String,
+struct IndustryCount {
     industry: String,
+    count: i64,
 }
 
-#[derive(Default, Clone, Copy, PartialEq, Deserialize, Serialize, Debug)]
-enum Status {
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub(crate) enum Status {
     #[default]
     ToDo,
     Solicitated,
     Pending,
     Accepted,
     Rejected,
+    /// Taken out of the running by the applicant.
+    Withdrawn,
 }
 
 impl std::fmt::Display for Status {
@@ -422,6 +8830,7 @@ impl std::fmt::Display for Status {
             Status::Pending => write!(f, "Pending"),
             Status::Accepted => write!(f, "Accepted"),
             Status::Rejected => write!(f, "Rejected"),
+            Status::Withdrawn => write!(f, "Withdrawn"),
         }
     }
 }
@@ -436,42 +8845,89 @@ impl std::str::FromStr for Status {
             "Pending" => Ok(Status::Pending),
             "Accepted" => Ok(Status::Accepted),
             "Rejected" => Ok(Status::Rejected),
+            "Withdrawn" => Ok(Status::Withdrawn),
             _ => Err(format!("Invalid status: {s}")),
         }
     }
 }
 
 impl Status {
-    #[cfg(feature = "ssr")]
-    fn as_str(&self) -> &'static str {
+    pub(crate) const ALL: [Status; 6] = [
+        Status::ToDo,
+        Status::Solicitated,
+        Status::Pending,
+        Status::Accepted,
+        Status::Rejected,
+        Status::Withdrawn,
+    ];
+
+    pub(crate) fn as_str(&self) -> &'static str {
         match self {
             Status::ToDo => "ToDo",
             Status::Solicitated => "Solicitated",
             Status::Pending => "Pending",
             Status::Accepted => "Accepted",
             Status::Rejected => "Rejected",
+            Status::Withdrawn => "Withdrawn",
         }
     }
 
-    fn css_class(&self) -> &'static str {
+    /// The name shown for the status in the UI.
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            Status::ToDo => "To Do",
+            Status::Solicitated => "Applied",
+            Status::Pending => "Pending",
+            Status::Accepted => "Accepted",
+            Status::Rejected => "Rejected",
+            Status::Withdrawn => "Withdrawn",
+        }
+    }
+
+    /// Whether the company has answered once an application reaches this
+    /// status.
+    fn is_response(&self) -> bool {
+        matches!(self, Status::Pending | Status::Accepted | Status::Rejected)
+    }
+
+    /// The statuses an application usually moves on to from this one.
+    fn next_steps(&self) -> &'static [Status] {
+        match self {
+            Status::ToDo => &[Status::Solicitated],
+            Status::Solicitated => &[Status::Pending, Status::Rejected],
+            Status::Pending => &[Status::Accepted, Status::Rejected],
+            Status::Accepted | Status::Rejected | Status::Withdrawn => &[],
+        }
+    }
+
+    /// Whether the application has run its course, with no step left after
+    /// this status.
+    #[cfg(feature = "ssr")]
+    fn is_finished(&self) -> bool {
+        self.next_steps().is_empty()
+    }
+
+    pub(crate) fn css_class(&self) -> &'static str {
         match self {
             Status::ToDo => "status-todo",
             Status::Solicitated => "status-solicitated",
             Status::Pending => "status-pending",
             Status::Accepted => "status-accepted",
             Status::Rejected => "status-rejected",
+            Status::Withdrawn => "status-withdrawn",
         }
     }
 }
 
 #[cfg(feature = "ssr")]
 impl Application {
-    pub fn new(company: &Company, status: Status) -> Self {
+    pub fn new(company: &Company, status: Status, deadline: Option<Date>) -> Self {
         Self {
             id: Uuid::new_v4(),
             company: company.clone(),
             status,
             date: OffsetDateTime::now_utc(),
+            deadline,
         }
     }
 }
@@ -490,3 +8946,1199 @@ impl Company {
         }
     }
 }
+
+/// Cleans up a website as typed or pasted: trims it, adds `https://` when no
+/// scheme is given and lowercases the scheme and host. The path is kept as
+/// is. Used by the form on blur and by the server before storing, so both
+/// agree on the stored value.
+fn normalize_website(input: &str) -> Result<String, WebsiteError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(WebsiteError::Empty);
+    }
+
+    let (scheme, rest) = match input.split_once("://") {
+        Some((scheme, rest)) => (scheme.to_lowercase(), rest),
+        None => ("https".to_string(), input),
+    };
+    if scheme != "http" && scheme != "https" {
+        return Err(WebsiteError::UnsupportedScheme);
+    }
+
+    let host_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (host, path) = rest.split_at(host_end);
+    let host = host.to_lowercase();
+    let valid_host = host.contains('.')
+        && !host.starts_with('.')
+        && !host.ends_with('.')
+        && host
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '.' | '-' | ':'));
+    if !valid_host {
+        return Err(WebsiteError::InvalidHost);
+    }
+
+    Ok(format!("{scheme}://{host}{path}"))
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum WebsiteError {
+    Empty,
+    UnsupportedScheme,
+    InvalidHost,
+}
+
+impl std::fmt::Display for WebsiteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebsiteError::Empty => write!(f, "website is required"),
+            WebsiteError::UnsupportedScheme => write!(f, "website must use http or https"),
+            WebsiteError::InvalidHost => write!(f, "website needs a domain such as example.com"),
+        }
+    }
+}
+
+/// Reported as invalid arguments, like [`DateError`].
+impl From<WebsiteError> for ServerFnError {
+    fn from(error: WebsiteError) -> Self {
+        ServerFnError::Args(error.to_string())
+    }
+}
+
+/// Reduces a website to the part that identifies the site: its lowercase host
+/// without scheme, `www.`, path or query.
+#[cfg(feature = "ssr")]
+fn website_key(website: &str) -> String {
+    let website = website.trim().to_lowercase();
+    let without_scheme = website
+        .split_once("://")
+        .map_or(website.as_str(), |(_, rest)| rest);
+    let host = without_scheme
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or_default();
+
+    host.strip_prefix("www.").unwrap_or(host).to_string()
+}
+
+/// Normalizes free-text industry input for matching: lowercase, with runs of
+/// whitespace collapsed to single spaces.
+#[cfg(feature = "ssr")]
+fn industry_key(industry: &str) -> String {
+    industry
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// How far ahead of the server clock an application date may be, to tolerate
+/// clients whose clocks run slightly fast.
+#[cfg(feature = "ssr")]
+const MAX_CLOCK_SKEW: time::Duration = time::Duration::minutes(5);
+
+/// How far into the future a follow-up may be scheduled.
+#[cfg(feature = "ssr")]
+const MAX_FOLLOW_UP_AHEAD: time::Duration = time::Duration::days(365);
+
+/// The timestamps of one application that must stay consistent with each other.
+#[cfg(feature = "ssr")]
+#[derive(Clone, Copy, Debug)]
+struct ApplicationDates {
+    applied_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+    follow_up: Option<Date>,
+}
+
+/// A way in which an application's timestamps contradict each other.
+#[cfg(feature = "ssr")]
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum DateError {
+    AppliedInFuture,
+    UpdatedBeforeApplied,
+    FollowUpBeforeApplied,
+    FollowUpTooFar,
+}
+
+#[cfg(feature = "ssr")]
+impl std::fmt::Display for DateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DateError::AppliedInFuture => write!(f, "Application date cannot be in the future"),
+            DateError::UpdatedBeforeApplied => {
+                write!(f, "Last update cannot be before the application date")
+            }
+            DateError::FollowUpBeforeApplied => {
+                write!(f, "Follow-up cannot be before the application date")
+            }
+            DateError::FollowUpTooFar => {
+                write!(f, "Follow-up cannot be more than a year ahead")
+            }
+        }
+    }
+}
+
+/// Reported as invalid arguments, so [`describe_error`] shows the message as
+/// a problem with the input rather than with the server.
+#[cfg(feature = "ssr")]
+impl From<DateError> for ServerFnError {
+    fn from(error: DateError) -> Self {
+        ServerFnError::Args(error.to_string())
+    }
+}
+
+/// Checks the invariants between an application's timestamps, so a timeline
+/// never runs backwards. Every create and update path calls this before
+/// writing.
+#[cfg(feature = "ssr")]
+fn validate_application_dates(
+    dates: &ApplicationDates,
+    now: OffsetDateTime,
+) -> Result<(), DateError> {
+    if dates.applied_at > now + MAX_CLOCK_SKEW {
+        return Err(DateError::AppliedInFuture);
+    }
+    if dates.updated_at < dates.applied_at {
+        return Err(DateError::UpdatedBeforeApplied);
+    }
+    if let Some(follow_up) = dates.follow_up {
+        if follow_up < dates.applied_at.date() {
+            return Err(DateError::FollowUpBeforeApplied);
+        }
+        if follow_up > (now + MAX_FOLLOW_UP_AHEAD).date() {
+            return Err(DateError::FollowUpTooFar);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    /// Makes `pool` available to the server functions the test calls. The
+    /// returned owner has to be kept alive for as long as they run.
+    fn provide_pool(pool: &SqlitePool) -> Owner {
+        let owner = Owner::new();
+        owner.set();
+        provide_context(pool.clone());
+        owner
+    }
+
+    /// Adds a company with `count` applications to it, `notes` on each, and
+    /// returns the company and application ids.
+    async fn insert_company_with_applications(
+        pool: &SqlitePool,
+        count: usize,
+        notes: &str,
+    ) -> (Uuid, Vec<Uuid>) {
+        let company = Company::new(
+            "Acme".into(),
+            "https://acme.example".into(),
+            "Wile E.".into(),
+            "Tech".into(),
+        );
+        let mut ids = Vec::new();
+        for i in 0..count {
+            let application = Application::new(&company, Status::Solicitated, None);
+            if i == 0 {
+                insert_application(pool, &application).await.unwrap();
+            } else {
+                sqlx::query(
+                    "INSERT INTO applications (id, company_id, status, date, updated_at) VALUES (?, ?, ?, ?, ?)",
+                )
+                .bind(application.id.to_string())
+                .bind(company.id.to_string())
+                .bind(application.status.as_str())
+                .bind(date_to_db(application.date))
+                .bind(date_to_db(application.date))
+                .execute(pool)
+                .await
+                .unwrap();
+            }
+            sqlx::query("UPDATE applications SET notes = ? WHERE id = ?")
+                .bind(format!("{notes} {i}"))
+                .bind(application.id.to_string())
+                .execute(pool)
+                .await
+                .unwrap();
+            ids.push(application.id);
+        }
+        (company.id, ids)
+    }
+
+    async fn company_exists(pool: &SqlitePool, id: Uuid) -> bool {
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM companies WHERE id = ?)")
+            .bind(id.to_string())
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    fn loaded(status: Status, updated_at: OffsetDateTime) -> AllApplicationsResponse {
+        let company = Company::new(
+            "Acme".into(),
+            "https://acme.example".into(),
+            String::new(),
+            "Tech".into(),
+        );
+        AllApplicationsResponse {
+            id: Uuid::new_v4(),
+            company,
+            status,
+            date: updated_at,
+            updated_at,
+            follow_up: None,
+            deadline: None,
+            completeness: 0,
+            notes: None,
+        }
+    }
+
+    #[sqlx::test]
+    async fn display_dates_are_rewritten_as_rfc3339(pool: SqlitePool) {
+        let rows = [
+            "2026-01-27 9:05:03.123456 +00:00:00",
+            "2026-01-27 23:05:03.5 +02:00:00",
+            "2026-01-27T09:05:03.123456Z",
+        ];
+        let mut ids = Vec::new();
+        for date in rows {
+            let company = Company::new(
+                "Acme".into(),
+                "https://acme.example".into(),
+                "Wile E.".into(),
+                "Tech".into(),
+            );
+            let application = Application::new(&company, Status::Solicitated, None);
+            insert_application(&pool, &application).await.unwrap();
+            sqlx::query("UPDATE applications SET date = ? WHERE id = ?")
+                .bind(date)
+                .bind(application.id.to_string())
+                .execute(&pool)
+                .await
+                .unwrap();
+            ids.push(application.id);
+        }
+
+        sqlx::raw_sql(include_str!(
+            "../migrations/20260203000000_rfc3339_dates.up.sql"
+        ))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let expected = [
+            "2026-01-27T09:05:03.123456Z",
+            "2026-01-27T23:05:03.5+02:00",
+            "2026-01-27T09:05:03.123456Z",
+        ];
+        for (id, expected) in ids.iter().zip(expected) {
+            let date: String = sqlx::query_scalar("SELECT date FROM applications WHERE id = ?")
+                .bind(id.to_string())
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+            assert_eq!(date, expected);
+            assert!(OffsetDateTime::parse(&date, &Rfc3339).is_ok(), "{date}");
+        }
+    }
+
+    #[test]
+    fn finished_applications_are_never_the_next_action() {
+        use time::macros::datetime;
+
+        let now = datetime!(2026-03-10 12:00 UTC);
+        let company = Company::new(
+            "Acme".into(),
+            "https://acme.example".into(),
+            "Wile E.".into(),
+            "Tech".into(),
+        );
+        for status in Status::ALL {
+            let application = AllApplicationsResponse {
+                date: now - time::Duration::days(30),
+                updated_at: now - time::Duration::days(30),
+                follow_up: Some(now.date() - time::Duration::days(3)),
+                ..Application::new(&company, status, None).into()
+            };
+            assert_eq!(
+                next_action_score(&application, now).is_none(),
+                status.is_finished(),
+                "{status:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn application_dates_are_checked_against_each_other() {
+        use time::macros::{date, datetime};
+
+        let now = datetime!(2026-02-10 12:00 UTC);
+        let valid = ApplicationDates {
+            applied_at: datetime!(2026-02-01 09:00 UTC),
+            updated_at: now,
+            follow_up: Some(date!(2026 - 02 - 15)),
+        };
+        assert_eq!(validate_application_dates(&valid, now), Ok(()));
+
+        // Within the allowed clock skew
+        let skewed = ApplicationDates {
+            applied_at: now + time::Duration::minutes(4),
+            updated_at: now + time::Duration::minutes(4),
+            follow_up: None,
+        };
+        assert_eq!(validate_application_dates(&skewed, now), Ok(()));
+
+        let cases = [
+            (
+                ApplicationDates {
+                    applied_at: now + time::Duration::minutes(6),
+                    updated_at: now + time::Duration::minutes(6),
+                    ..valid
+                },
+                DateError::AppliedInFuture,
+            ),
+            (
+                ApplicationDates {
+                    updated_at: datetime!(2026-01-31 09:00 UTC),
+                    ..valid
+                },
+                DateError::UpdatedBeforeApplied,
+            ),
+            (
+                ApplicationDates {
+                    follow_up: Some(date!(2026 - 01 - 31)),
+                    ..valid
+                },
+                DateError::FollowUpBeforeApplied,
+            ),
+            (
+                ApplicationDates {
+                    follow_up: Some((now + MAX_FOLLOW_UP_AHEAD).date().next_day().unwrap()),
+                    ..valid
+                },
+                DateError::FollowUpTooFar,
+            ),
+        ];
+        for (dates, error) in cases {
+            assert_eq!(validate_application_dates(&dates, now), Err(error));
+        }
+    }
+
+    #[test]
+    fn rejected_dates_read_as_invalid_input() {
+        assert_eq!(
+            describe_error(&DateError::AppliedInFuture.into()),
+            "Invalid input: Application date cannot be in the future"
+        );
+    }
+
+    #[sqlx::test]
+    async fn industries_match_regardless_of_non_ascii_case(pool: SqlitePool) {
+        sqlx::query("INSERT INTO industries (id, name) VALUES ('energie', 'Énergie')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let mut tx = pool.begin().await.unwrap();
+
+        for typed in ["ÉNERGIE", "  énergie ", "Énergie"] {
+            let resolved = resolve_industry(&mut tx, typed).await.unwrap();
+            assert_eq!(resolved.as_deref(), Some("energie"), "{typed:?}");
+        }
+        let resolved = resolve_industry(&mut tx, "Fintech").await.unwrap();
+        assert_eq!(resolved.as_deref(), Some("finance"));
+    }
+
+    #[test]
+    fn application_ids_from_forms_are_trimmed_and_checked() {
+        let id = Uuid::new_v4();
+        assert_eq!(parse_application_id(&format!(" {id}\n")).unwrap(), id);
+        let error = parse_application_id("not-an-id").unwrap_err();
+        assert_eq!(
+            describe_error(&error),
+            "Invalid input: Invalid application id: \"not-an-id\""
+        );
+        assert_eq!(
+            describe_error(&WebsiteError::Empty.into()),
+            "Invalid input: website is required"
+        );
+    }
+
+    #[sqlx::test]
+    async fn deleting_or_updating_an_unknown_application_says_so(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let unknown = Uuid::new_v4();
+        let not_found = ServerFnError::new(format!("Application {unknown} not found")).to_string();
+
+        let deleted = delete_application(unknown.to_string()).await;
+        assert_eq!(deleted.unwrap_err().to_string(), not_found);
+        let updated = update_application_status(unknown.to_string(), Status::Pending).await;
+        assert_eq!(updated.unwrap_err().to_string(), not_found);
+        let malformed = update_application_status("42".into(), Status::Pending).await;
+        assert!(malformed
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid application id"));
+    }
+
+    #[test]
+    fn timestamps_round_trip_across_leap_days_and_midnight() {
+        use time::macros::datetime;
+
+        let cases = [
+            (
+                datetime!(2028-02-29 00:00 UTC),
+                "2028-02-29T00:00:00.000000000Z",
+            ),
+            (
+                datetime!(2028-02-29 23:59:59.999999999 UTC),
+                "2028-02-29T23:59:59.999999999Z",
+            ),
+            // Midnight elsewhere is still the day before in UTC
+            (
+                datetime!(2028-03-01 00:30 +01:00),
+                "2028-02-29T23:30:00.000000000Z",
+            ),
+            (
+                datetime!(2028-02-29 23:30 -01:00),
+                "2028-03-01T00:30:00.000000000Z",
+            ),
+        ];
+        for (at, stored) in cases {
+            assert_eq!(date_to_db(at), stored);
+            assert_eq!(date_from_db(stored).unwrap(), at);
+        }
+
+        // Older rows with another offset or fewer digits still load
+        assert_eq!(
+            date_from_db("2028-03-01T00:30:00.5+01:00").unwrap(),
+            datetime!(2028-02-29 23:30:00.5 UTC),
+        );
+        assert!(
+            date_to_db(datetime!(2028-03-01 00:30 +01:00))
+                < date_to_db(datetime!(2028-02-29 23:59:59.9 UTC))
+        );
+    }
+
+    #[test]
+    fn stored_dates_only_accept_real_days() {
+        assert!(Date::parse("2028-02-29", DB_DATE).is_ok());
+        assert!(Date::parse("2027-02-29", DB_DATE).is_err());
+        assert!(Date::parse("2028-02-29T00:00:00Z", DB_DATE).is_err());
+    }
+
+    /// Percent-encodes `value` for a form body, as the browser does for the
+    /// server function's arguments.
+    fn form_encode(value: &str) -> String {
+        value
+            .bytes()
+            .map(|byte| match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'*' => {
+                    char::from(byte).to_string()
+                }
+                _ => format!("%{byte:02X}"),
+            })
+            .collect()
+    }
+
+    #[sqlx::test]
+    async fn import_of_5000_records_fits_through_http(pool: SqlitePool) {
+        use axum::{body::Body, http::Request, middleware, routing::post, Router};
+        use leptos::server_fn::ServerFn;
+        use tower::ServiceExt;
+
+        let now = OffsetDateTime::now_utc() - time::Duration::days(1);
+        let records: Vec<_> = (0..5000)
+            .map(|_| loaded(Status::Solicitated, now))
+            .collect();
+        let json = serde_json::to_string(&records).unwrap();
+        let body = format!("json={}&conflict_mode=Skip", form_encode(&json));
+        assert!(body.len() > crate::api::MAX_REQUEST_BODY_BYTES);
+
+        let handler_pool = pool.clone();
+        let app = Router::new()
+            .route(
+                ImportApplications::PATH,
+                post(move |request| {
+                    let pool = handler_pool.clone();
+                    leptos_axum::handle_server_fns_with_context(
+                        move || provide_context(pool.clone()),
+                        request,
+                    )
+                }),
+            )
+            .layer(middleware::from_fn(crate::api::limit_page_bodies));
+        let request = Request::post(ImportApplications::PATH)
+            .header("content-type", "application/x-www-form-urlencoded")
+            .header("content-length", body.len())
+            .body(Body::from(body))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let imported: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM applications")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(imported, 5000);
+    }
+
+    #[sqlx::test]
+    async fn older_dates_are_normalized_by_the_migration(pool: SqlitePool) {
+        let mut ids = Vec::new();
+        for name in ["Acme", "Globex"] {
+            let company = Company::new(
+                name.into(),
+                format!("https://{}.example", name.to_lowercase()),
+                "Wile E.".into(),
+                "Tech".into(),
+            );
+            let application = Application::new(&company, Status::Solicitated, None);
+            insert_application(&pool, &application).await.unwrap();
+            ids.push(application.id);
+        }
+        let rows = [
+            (
+                ids[0],
+                "2028-03-01T00:30:00.5+01:00",
+                "2028-02-29T23:30:00-01:00",
+            ),
+            (ids[1], "2028-02-29T00:00:00Z", "2027-02-29T10:00:00Z"),
+        ];
+        for (id, date, follow_up) in rows {
+            sqlx::query(
+                "UPDATE applications SET date = ?, follow_up = ?, deadline = ? WHERE id = ?",
+            )
+            .bind(date)
+            .bind(follow_up)
+            .bind(follow_up)
+            .bind(id.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        sqlx::raw_sql(include_str!(
+            "../migrations/20260219000000_normalize_timestamps.up.sql"
+        ))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let expected = [
+            (ids[0], "2028-02-29T23:30:00.500000000Z", "2028-02-29"),
+            // Not a real day, so left for the user to fix
+            (
+                ids[1],
+                "2028-02-29T00:00:00.000000000Z",
+                "2027-02-29T10:00:00Z",
+            ),
+        ];
+        for (id, date, follow_up) in expected {
+            let row: (String, String, String) =
+                sqlx::query_as("SELECT date, follow_up, deadline FROM applications WHERE id = ?")
+                    .bind(id.to_string())
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+            assert_eq!(row, (date.into(), follow_up.into(), follow_up.into()));
+        }
+    }
+
+    #[sqlx::test]
+    async fn detail_comes_with_the_first_page_of_attachments(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let company = Company::new(
+            "Acme".into(),
+            "https://acme.example".into(),
+            "Wile E.".into(),
+            "Tech".into(),
+        );
+        let application = Application::new(&company, Status::Solicitated, None);
+        insert_application(&pool, &application).await.unwrap();
+        sqlx::query(
+            "INSERT INTO attachments (id, application_id, file_name, content_type, size, data, uploaded_at) VALUES (?, ?, 'cv.pdf', 'application/pdf', 3, x'255044', ?)",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(application.id.to_string())
+        .bind(date_to_db(OffsetDateTime::now_utc()))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let detail = get_application_detail(application.id).await.unwrap();
+
+        assert_eq!(
+            detail.attachments,
+            list_attachments(application.id, ATTACHMENTS_PAGE_SIZE, 0)
+                .await
+                .unwrap()
+        );
+        assert_eq!(detail.attachments.total, 1);
+        assert_eq!(detail.attachments.attachments[0].file_name, "cv.pdf");
+    }
+
+    #[test]
+    fn applied_counts_follow_the_preferred_statuses() {
+        let by_status: Vec<(Status, i64)> = Status::ALL.into_iter().zip(1..).collect();
+        let mut preferences = Preferences::default();
+
+        // Everything but To Do (1); Pending, Accepted and Rejected answered
+        assert_eq!(preferences.applied_and_responded(&by_status), (20, 12));
+
+        preferences.applied_statuses = vec![Status::Pending, Status::Withdrawn];
+        assert_eq!(preferences.applied_and_responded(&by_status), (9, 3));
+
+        preferences.applied_statuses.clear();
+        assert_eq!(preferences.applied_and_responded(&by_status), (0, 0));
+    }
+
+    #[sqlx::test]
+    async fn response_times_start_at_the_first_applied_status(pool: SqlitePool) {
+        use time::macros::datetime;
+
+        let _owner = provide_pool(&pool);
+        let company = Company::new(
+            "Acme".into(),
+            "https://acme.example".into(),
+            "Wile E.".into(),
+            "Tech".into(),
+        );
+        let application = Application::new(&company, Status::Solicitated, None);
+        insert_application(&pool, &application).await.unwrap();
+        sqlx::query("DELETE FROM status_history")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let history = [
+            (Status::ToDo, datetime!(2026-02-01 09:00 UTC)),
+            (Status::Solicitated, datetime!(2026-02-03 09:00 UTC)),
+            (Status::Pending, datetime!(2026-02-06 09:00 UTC)),
+        ];
+        for (status, at) in history {
+            sqlx::query(
+                "INSERT INTO status_history (application_id, status, changed_at) VALUES (?, ?, ?)",
+            )
+            .bind(application.id.to_string())
+            .bind(status.as_str())
+            .bind(date_to_db(at))
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        let times = get_company_response_times().await.unwrap();
+        assert_eq!(times[0].average_days, 3.0);
+
+        let mut preferences = load_preferences(&pool).await.unwrap();
+        preferences.applied_statuses = Status::ALL.to_vec();
+        save_preferences(preferences).await.unwrap();
+        let times = get_company_response_times().await.unwrap();
+        assert_eq!(times[0].average_days, 5.0);
+    }
+
+    #[sqlx::test]
+    async fn today_leaves_out_follow_ups_of_finished_applications(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let yesterday = (OffsetDateTime::now_utc().date() - time::Duration::days(1))
+            .format(DB_DATE)
+            .unwrap();
+        let mut ids = Vec::new();
+        for status in [Status::Pending, Status::Rejected] {
+            let company = Company::new(
+                status.to_string(),
+                format!("https://{}.example", status.as_str().to_lowercase()),
+                "Wile E.".into(),
+                "Tech".into(),
+            );
+            let application = Application::new(&company, status, None);
+            insert_application(&pool, &application).await.unwrap();
+            sqlx::query("UPDATE applications SET follow_up = ? WHERE id = ?")
+                .bind(&yesterday)
+                .bind(application.id.to_string())
+                .execute(&pool)
+                .await
+                .unwrap();
+            ids.push(application.id);
+        }
+
+        let today = get_today().await.unwrap();
+
+        let follow_ups: Vec<Uuid> = today.follow_ups.iter().map(|a| a.id).collect();
+        assert_eq!(follow_ups, vec![ids[0]]);
+        assert!(today.to_do.is_empty() && today.deadlines.is_empty());
+    }
+
+    #[test]
+    fn every_age_falls_in_exactly_one_bucket() {
+        for days in -30..400 {
+            let buckets: Vec<AgeBucket> = AgeBucket::ALL
+                .into_iter()
+                .filter(|bucket| bucket.contains(days))
+                .collect();
+            assert_eq!(buckets.len(), 1, "{days} days old is in {buckets:?}");
+        }
+        assert!(AgeBucket::Week.contains(-3));
+        assert!(AgeBucket::Quarter.contains(90));
+        assert!(AgeBucket::Older.contains(91));
+    }
+
+    #[sqlx::test]
+    async fn accepting_an_offer_withdraws_only_applications_in_the_running(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let statuses = [
+            Status::Pending,
+            Status::Solicitated,
+            Status::ToDo,
+            Status::Accepted,
+            Status::Rejected,
+        ];
+        let mut ids = Vec::new();
+        for status in statuses {
+            let company = Company::new(
+                status.to_string(),
+                format!("https://{}.example", status.as_str().to_lowercase()),
+                "Wile E.".into(),
+                "Tech".into(),
+            );
+            let application = Application::new(&company, status, None);
+            insert_application(&pool, &application).await.unwrap();
+            ids.push(application.id);
+        }
+
+        assert_eq!(withdraw_other_applications(ids[3]).await.unwrap(), 2);
+
+        for (id, expected) in ids.iter().zip([
+            Status::Withdrawn,
+            Status::Withdrawn,
+            Status::ToDo,
+            Status::Accepted,
+            Status::Rejected,
+        ]) {
+            let status: String = sqlx::query_scalar("SELECT status FROM applications WHERE id = ?")
+                .bind(id.to_string())
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+            assert_eq!(status, expected.as_str());
+        }
+    }
+
+    #[sqlx::test]
+    async fn importing_into_an_existing_company_keeps_it_shared(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let company = Company::new(
+            "Acme".into(),
+            "https://acme.example".into(),
+            "Wile E.".into(),
+            "Tech".into(),
+        );
+        let application = Application::new(&company, Status::Solicitated, None);
+        insert_application(&pool, &application).await.unwrap();
+        let mut records = fetch_applications(&pool, &ApplicationFilter::default())
+            .await
+            .unwrap();
+        let imported = Uuid::new_v4();
+        records[0].id = imported;
+
+        let outcomes =
+            import_applications(serde_json::to_string(&records).unwrap(), ConflictMode::Skip)
+                .await
+                .unwrap();
+        assert_eq!(outcomes[0].result, ImportResult::Created);
+
+        // Either application going away leaves the company to the other
+        delete_application(application.id.to_string())
+            .await
+            .unwrap();
+        assert!(company_exists(&pool, company.id).await);
+        delete_application(imported.to_string()).await.unwrap();
+        assert!(!company_exists(&pool, company.id).await);
+    }
+
+    #[sqlx::test]
+    async fn archived_notes_come_back_with_the_import(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let company = Company::new(
+            "Acme".into(),
+            "https://acme.example".into(),
+            "Wile E.".into(),
+            "Tech".into(),
+        );
+        let application = Application::new(&company, Status::Solicitated, None);
+        insert_application(&pool, &application).await.unwrap();
+        set_notes(application.id, "Spoke to Ada".into())
+            .await
+            .unwrap();
+        let tomorrow = OffsetDateTime::now_utc().date() + time::Duration::days(1);
+
+        let export = archive_and_export(vec![Status::Solicitated], tomorrow)
+            .await
+            .unwrap();
+        assert_eq!(
+            export.applications[0].notes.as_deref(),
+            Some("Spoke to Ada")
+        );
+
+        delete_application(application.id.to_string())
+            .await
+            .unwrap();
+        import_applications(
+            serde_json::to_string(&export.applications).unwrap(),
+            ConflictMode::Skip,
+        )
+        .await
+        .unwrap();
+
+        let notes: String = sqlx::query_scalar("SELECT notes FROM applications WHERE id = ?")
+            .bind(application.id.to_string())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(notes, "Spoke to Ada");
+    }
+
+    #[sqlx::test]
+    async fn note_edits_show_up_in_the_timeline(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let company = Company::new(
+            "Acme".into(),
+            "https://acme.example".into(),
+            "Wile E.".into(),
+            "Tech".into(),
+        );
+        let application = Application::new(&company, Status::Solicitated, None);
+        insert_application(&pool, &application).await.unwrap();
+
+        set_notes(application.id, "Called the recruiter".into())
+            .await
+            .unwrap();
+
+        let page = get_activity_timeline(TIMELINE_PAGE_SIZE, 0).await.unwrap();
+        assert_eq!(page.events[0].kind, ActivityKind::NotesEdited);
+        assert_eq!(page.events[0].application_id, application.id);
+    }
+
+    #[sqlx::test]
+    async fn status_change_for_all_matching_follows_the_filter(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let mut ids = Vec::new();
+        for (i, status) in [Status::Pending, Status::Solicitated, Status::Solicitated]
+            .into_iter()
+            .enumerate()
+        {
+            let company = Company::new(
+                format!("Acme {i}"),
+                format!("https://acme{i}.example"),
+                "Wile E.".into(),
+                "Tech".into(),
+            );
+            let application = Application::new(&company, status, None);
+            insert_application(&pool, &application).await.unwrap();
+            ids.push(application.id);
+        }
+
+        let counts = count_matching_statuses(ApplicationFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            counts,
+            HashMap::from([(Status::Solicitated, 2), (Status::Pending, 1)])
+        );
+
+        let filter = ApplicationFilter {
+            status: Some(Status::Solicitated),
+            ..ApplicationFilter::default()
+        };
+        let updated = bulk_update_status_matching(filter, Status::Rejected)
+            .await
+            .unwrap();
+
+        assert_eq!(updated, 2);
+        let statuses: Vec<(String, String)> =
+            sqlx::query_as("SELECT id, status FROM applications ORDER BY status")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(statuses[0], (ids[0].to_string(), "Pending".to_string()));
+        assert!(statuses[1..].iter().all(|(_, status)| status == "Rejected"));
+        let recorded: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM status_history WHERE status = 'Rejected'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(recorded, 2);
+    }
+
+    #[sqlx::test]
+    async fn undoing_a_follow_up_advance_restores_the_follow_up(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let company = Company::new(
+            "Acme".into(),
+            "https://acme.example".into(),
+            "Wile E.".into(),
+            "Tech".into(),
+        );
+        let application = Application::new(&company, Status::Solicitated, None);
+        insert_application(&pool, &application).await.unwrap();
+        let follow_up = OffsetDateTime::now_utc().date();
+        sqlx::query("UPDATE applications SET follow_up = ? WHERE id = ?")
+            .bind(follow_up.format(DB_DATE).unwrap())
+            .bind(application.id.to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+        let mut preferences = load_preferences(&pool).await.unwrap();
+        preferences.follow_up_advance = FollowUpAdvanceMode::Auto;
+        save_preferences(preferences).await.unwrap();
+
+        let completion = complete_follow_up(application.id).await.unwrap();
+        assert!(completion.applied);
+        assert_eq!(completion.follow_up, follow_up);
+
+        undo_follow_up_advance(application.id, completion.previous, completion.follow_up)
+            .await
+            .unwrap();
+
+        let (status, restored): (String, Option<String>) =
+            sqlx::query_as("SELECT status, follow_up FROM applications WHERE id = ?")
+                .bind(application.id.to_string())
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(status, Status::Solicitated.as_str());
+        assert_eq!(restored, Some(follow_up.format(DB_DATE).unwrap()));
+    }
+
+    #[sqlx::test]
+    async fn merging_duplicates_of_one_company_keeps_the_company(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let (company, ids) = insert_company_with_applications(&pool, 2, "note").await;
+
+        let deleted = merge_duplicate_applications(ids[0], vec![ids[1]])
+            .await
+            .unwrap();
+
+        assert_eq!(deleted, 1);
+        assert!(company_exists(&pool, company).await);
+        let remaining: Vec<(String, String)> =
+            sqlx::query_as("SELECT id, notes FROM applications WHERE company_id = ?")
+                .bind(company.to_string())
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            remaining,
+            vec![(ids[0].to_string(), "note 0\n\nnote 1".to_string())]
+        );
+    }
+
+    #[sqlx::test]
+    async fn merging_drops_the_history_of_the_duplicates(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let (_, ids) = insert_company_with_applications(&pool, 2, "note").await;
+        for id in &ids {
+            sqlx::query(
+                "INSERT INTO status_history (application_id, status, changed_at) VALUES (?, 'Solicitated', ?)",
+            )
+            .bind(id.to_string())
+            .bind(date_to_db(OffsetDateTime::now_utc()))
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        let kept_history = |pool: SqlitePool, id: Uuid| async move {
+            sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM status_history WHERE application_id = ?",
+            )
+            .bind(id.to_string())
+            .fetch_one(&pool)
+            .await
+            .unwrap()
+        };
+        let before = kept_history(pool.clone(), ids[0]).await;
+
+        merge_duplicate_applications(ids[0], vec![ids[1]])
+            .await
+            .unwrap();
+
+        assert_eq!(kept_history(pool.clone(), ids[0]).await, before);
+        let orphaned: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM status_history WHERE application_id = ?")
+                .bind(ids[1].to_string())
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(orphaned, 0);
+    }
+
+    #[sqlx::test]
+    async fn merging_an_unknown_or_archived_duplicate_is_not_found(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let (_, ids) = insert_company_with_applications(&pool, 2, "note").await;
+        sqlx::query("UPDATE applications SET archived_at = ? WHERE id = ?")
+            .bind(date_to_db(OffsetDateTime::now_utc()))
+            .bind(ids[1].to_string())
+            .execute(&pool)
+            .await
+            .unwrap();
+        let unknown = Uuid::new_v4();
+
+        for duplicate in [ids[1], unknown] {
+            let merged = merge_duplicate_applications(ids[0], vec![duplicate]).await;
+            assert_eq!(
+                merged.unwrap_err().to_string(),
+                ServerFnError::new(format!("Application {duplicate} not found")).to_string()
+            );
+        }
+        let left: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM applications")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(left, 2);
+    }
+
+    #[sqlx::test]
+    async fn pipeline_templates_round_trip(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let searching = ApplicationFilter {
+            status: Some(Status::Pending),
+            search: Some("Acme".into()),
+            search_notes: true,
+            ..ApplicationFilter::default()
+        };
+        let mut preferences = load_preferences(&pool).await.unwrap();
+        preferences.pixel_font = false;
+        preferences.default_view = searching.clone();
+        save_preferences(preferences).await.unwrap();
+        save_filter_preset("Waiting".into(), searching)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO industries (id, name) VALUES ('robotics', 'Robotics')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO industry_aliases (alias, industry_id) VALUES ('robots', 'robotics')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let template = export_pipeline_template().await.unwrap();
+        let waiting = ApplicationFilter {
+            status: Some(Status::Pending),
+            ..ApplicationFilter::default()
+        };
+        assert_eq!(template.preferences.default_view, waiting);
+        assert_eq!(template.filter_presets[0].filter, waiting);
+
+        // Into an empty tracker, everything comes back
+        sqlx::query("DELETE FROM filter_presets")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM industries WHERE id = 'robotics'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        save_preferences(Preferences::default()).await.unwrap();
+        let json = serde_json::to_string(&template).unwrap();
+        let imported = import_pipeline_template(json.clone()).await.unwrap();
+        assert_eq!(
+            imported,
+            TemplateImport {
+                filter_presets: 1,
+                industries: 1,
+                aliases: 1,
+            }
+        );
+        assert_eq!(export_pipeline_template().await.unwrap(), template);
+
+        // Importing it again only replaces the preferences
+        let imported = import_pipeline_template(json).await.unwrap();
+        assert_eq!(imported, TemplateImport::default());
+        assert_eq!(export_pipeline_template().await.unwrap(), template);
+    }
+
+    #[sqlx::test]
+    async fn changing_an_unknown_reminder_says_so(pool: SqlitePool) {
+        let _owner = provide_pool(&pool);
+        let unknown = Uuid::new_v4();
+        let not_found = format!("Reminder {unknown} not found");
+
+        let error = delete_reminder(unknown).await.unwrap_err();
+        assert!(error.to_string().contains(&not_found), "{error}");
+        let error = set_reminder_done(unknown, true).await.unwrap_err();
+        assert!(error.to_string().contains(&not_found), "{error}");
+    }
+
+    #[test]
+    fn a_failed_status_change_only_reverts_its_own_card() {
+        use time::macros::datetime;
+
+        let owner = Owner::new();
+        owner.set();
+        let pending = PendingEdits::default();
+        let loaded_at = datetime!(2026-03-10 12:00 UTC);
+        let first = loaded(Status::Solicitated, loaded_at);
+        let second = loaded(Status::Solicitated, loaded_at);
+
+        pending.set_status(first.id, Status::Pending, loaded_at);
+        pending.set_status(second.id, Status::Rejected, loaded_at);
+        pending.discard_latest_status();
+
+        assert_eq!(pending.status(first.id), Some(Status::Pending));
+        assert_eq!(pending.status(second.id), None);
+        // A refetch from before the change keeps the card as the user left it
+        assert_eq!(pending.reconcile(first.clone()).status, Status::Pending);
+    }
+
+    #[test]
+    fn a_newer_load_replaces_an_unreported_status_change() {
+        use time::macros::datetime;
+
+        let owner = Owner::new();
+        owner.set();
+        let pending = PendingEdits::default();
+        let loaded_at = datetime!(2026-03-10 12:00 UTC);
+        let application = loaded(Status::Solicitated, loaded_at);
+        pending.set_status(application.id, Status::Pending, loaded_at);
+
+        let reloaded = AllApplicationsResponse {
+            status: Status::Accepted,
+            updated_at: datetime!(2026-03-10 12:05 UTC),
+            ..application.clone()
+        };
+        assert_eq!(pending.reconcile(reloaded).status, Status::Accepted);
+        assert_eq!(pending.status(application.id), None);
+    }
+
+    #[test]
+    fn quiet_hours_hold_back_until_their_local_end() {
+        use time::macros::datetime;
+
+        // 22:00 to 08:00 in UTC+2
+        let quiet = QuietHours {
+            start: 22,
+            end: 8,
+            utc_offset_minutes: 120,
+        };
+
+        // 23:30 local waits for 08:00 local the next morning
+        assert_eq!(
+            quiet.next_allowed(datetime!(2026-03-10 21:30 UTC)),
+            datetime!(2026-03-11 06:00 UTC)
+        );
+        // 01:00 local waits for 08:00 the same morning
+        assert_eq!(
+            quiet.next_allowed(datetime!(2026-03-10 23:00 UTC)),
+            datetime!(2026-03-11 06:00 UTC)
+        );
+        // 21:00 local is still outside the window
+        let evening = datetime!(2026-03-10 19:00 UTC);
+        assert_eq!(quiet.next_allowed(evening), evening);
+    }
+}
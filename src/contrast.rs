@@ -0,0 +1,85 @@
+//! Checks that the status palette in the stylesheet stays readable.
+
+use crate::app::Status;
+
+/// The stylesheet whose `--status-*` variables color the status badges.
+const STYLESHEET: &str = include_str!("../style/main.css");
+
+/// WCAG AA's minimum contrast ratio for normal-sized text.
+pub const AA_CONTRAST: f64 = 4.5;
+
+/// Verifies that every status's `-fg` color on its `-bg` color meets
+/// [`AA_CONTRAST`], describing each pair that does not.
+pub fn check_status_palette() -> Result<(), String> {
+    let failures: Vec<String> = Status::ALL
+        .into_iter()
+        .filter_map(|status| {
+            let class = status.css_class();
+            let colors = stylesheet_color(&format!("--{class}-fg"))
+                .zip(stylesheet_color(&format!("--{class}-bg")));
+            match colors {
+                None => Some(format!("{class}: missing or not a #rrggbb color")),
+                Some((fg, bg)) => {
+                    let ratio = contrast_ratio(fg, bg);
+                    (ratio < AA_CONTRAST).then(|| format!("{class}: {ratio:.2}:1"))
+                }
+            }
+        })
+        .collect();
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "status colors below {AA_CONTRAST}:1 contrast: {}",
+            failures.join(", ")
+        ))
+    }
+}
+
+/// The value of the custom property `name` in the stylesheet, as RGB.
+fn stylesheet_color(name: &str) -> Option<[u8; 3]> {
+    let start = STYLESHEET.find(&format!("{name}:"))? + name.len() + 1;
+    let value = STYLESHEET[start..].split(';').next()?.trim();
+    let hex = value.strip_prefix('#').filter(|hex| hex.len() == 6)?;
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// The WCAG contrast ratio between two colors, from 1 to 21.
+fn contrast_ratio(a: [u8; 3], b: [u8; 3]) -> f64 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// The relative luminance of an sRGB color, as WCAG defines it.
+fn relative_luminance(rgb: [u8; 3]) -> f64 {
+    let linear = |c: u8| {
+        let c = f64::from(c) / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * linear(rgb[0]) + 0.7152 * linear(rgb[1]) + 0.0722 * linear(rgb[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_palette_meets_aa() {
+        check_status_palette().unwrap();
+    }
+
+    #[test]
+    fn contrast_ratio_matches_known_pairs() {
+        assert!((contrast_ratio([0, 0, 0], [255, 255, 255]) - 21.0).abs() < 1e-9);
+        assert!((contrast_ratio([119, 119, 119], [255, 255, 255]) - 4.48).abs() < 0.01);
+        assert_eq!(contrast_ratio([18, 52, 86], [18, 52, 86]), 1.0);
+    }
+}
@@ -0,0 +1,388 @@
+//! The weekly digest: a summary of the past week and the one ahead.
+//!
+//! Gathering the data ([`load_digest_data`]) is kept apart from turning it
+//! into text ([`compose_digest`]), which is pure, and from delivery, which
+//! for now is a log line written by [`spawn_weekly_digest`]. A digest that
+//! falls due during the quiet hours of the preferences waits for them to end.
+//! When it last went out is kept in the database, so it stays weekly across
+//! restarts.
+
+use crate::app::{date_from_db, date_to_db, load_preferences, Status, DB_DATE};
+use sqlx::SqlitePool;
+use time::{Date, Duration, OffsetDateTime};
+
+/// How often the digest is composed.
+const DIGEST_INTERVAL: Duration = Duration::weeks(1);
+
+/// Active applications waiting on a response that have not been touched for
+/// this long are listed as stale.
+const STALE_AFTER: Duration = Duration::days(21);
+
+/// What the digest reports on, for the week ending at `now`.
+pub(crate) struct DigestData {
+    pub(crate) now: OffsetDateTime,
+    pub(crate) new_applications: Vec<DigestEntry>,
+    pub(crate) status_changes: Vec<DigestEntry>,
+    pub(crate) upcoming_follow_ups: Vec<FollowUpEntry>,
+    pub(crate) stale: Vec<StaleEntry>,
+}
+
+pub(crate) struct DigestEntry {
+    pub(crate) company: String,
+    pub(crate) status: Status,
+}
+
+pub(crate) struct FollowUpEntry {
+    pub(crate) company: String,
+    pub(crate) date: Date,
+}
+
+pub(crate) struct StaleEntry {
+    pub(crate) company: String,
+    pub(crate) status: Status,
+    pub(crate) idle_days: i64,
+}
+
+/// A composed digest, ready to be sent as a multipart email.
+pub(crate) struct Digest {
+    pub(crate) subject: String,
+    pub(crate) text: String,
+    pub(crate) html: String,
+}
+
+/// Collects the week's activity from the database.
+pub(crate) async fn load_digest_data(
+    pool: &SqlitePool,
+    now: OffsetDateTime,
+) -> Result<DigestData, sqlx::Error> {
+    let week_start = date_to_db(now - DIGEST_INTERVAL);
+    let today = now.date().format(DB_DATE).unwrap_or_default();
+    let week_ahead = (now.date() + DIGEST_INTERVAL)
+        .format(DB_DATE)
+        .unwrap_or_default();
+
+    let new_applications: Vec<(String, String)> = sqlx::query_as(
+        r#"
+        SELECT c.name, a.status
+        FROM applications a JOIN companies c ON a.company_id = c.id
+        WHERE a.archived_at IS NULL AND a.date >= ?
+        ORDER BY a.date
+        "#,
+    )
+    .bind(&week_start)
+    .fetch_all(pool)
+    .await?;
+
+    // The first history entry of an application is its creation, which is
+    // already reported as new
+    let status_changes: Vec<(String, String)> = sqlx::query_as(
+        r#"
+        SELECT c.name, h.status
+        FROM status_history h
+        JOIN applications a ON h.application_id = a.id
+        JOIN companies c ON a.company_id = c.id
+        WHERE a.archived_at IS NULL AND h.changed_at >= ? AND h.changed_at != a.date
+        ORDER BY h.changed_at
+        "#,
+    )
+    .bind(&week_start)
+    .fetch_all(pool)
+    .await?;
+
+    let upcoming_follow_ups: Vec<(String, String)> = sqlx::query_as(
+        r#"
+        SELECT c.name, a.follow_up
+        FROM applications a JOIN companies c ON a.company_id = c.id
+        WHERE a.archived_at IS NULL AND a.follow_up BETWEEN ? AND ?
+        ORDER BY a.follow_up
+        "#,
+    )
+    .bind(&today)
+    .bind(&week_ahead)
+    .fetch_all(pool)
+    .await?;
+
+    let stale: Vec<(String, String, String)> = sqlx::query_as(
+        r#"
+        SELECT c.name, a.status, a.updated_at
+        FROM applications a JOIN companies c ON a.company_id = c.id
+        WHERE a.archived_at IS NULL AND a.status IN (?, ?) AND a.updated_at < ?
+        ORDER BY a.updated_at
+        "#,
+    )
+    .bind(Status::Solicitated.as_str())
+    .bind(Status::Pending.as_str())
+    .bind(date_to_db(now - STALE_AFTER))
+    .fetch_all(pool)
+    .await?;
+
+    let entries = |rows: Vec<(String, String)>| {
+        rows.into_iter()
+            .filter_map(|(company, status)| {
+                Some(DigestEntry {
+                    company,
+                    status: status.parse().ok()?,
+                })
+            })
+            .collect()
+    };
+
+    Ok(DigestData {
+        now,
+        new_applications: entries(new_applications),
+        status_changes: entries(status_changes),
+        upcoming_follow_ups: upcoming_follow_ups
+            .into_iter()
+            .filter_map(|(company, date)| {
+                Some(FollowUpEntry {
+                    company,
+                    date: Date::parse(&date, DB_DATE).ok()?,
+                })
+            })
+            .collect(),
+        stale: stale
+            .into_iter()
+            .filter_map(|(company, status, updated_at)| {
+                let updated_at = date_from_db(&updated_at).ok()?;
+                Some(StaleEntry {
+                    company,
+                    status: status.parse().ok()?,
+                    idle_days: (now - updated_at).whole_days(),
+                })
+            })
+            .collect(),
+    })
+}
+
+/// Renders the digest as plaintext and HTML. Sections with nothing to report
+/// are left out of both.
+pub(crate) fn compose_digest(data: &DigestData) -> Digest {
+    let sections = [
+        (
+            "New applications",
+            data.new_applications
+                .iter()
+                .map(|e| format!("{} ({})", e.company, e.status.label()))
+                .collect::<Vec<_>>(),
+        ),
+        (
+            "Status changes",
+            data.status_changes
+                .iter()
+                .map(|e| format!("{} moved to {}", e.company, e.status.label()))
+                .collect(),
+        ),
+        (
+            "Follow-ups this week",
+            data.upcoming_follow_ups
+                .iter()
+                .map(|e| format!("{} on {}", e.company, e.date))
+                .collect(),
+        ),
+        (
+            "Waiting for a while",
+            data.stale
+                .iter()
+                .map(|e| {
+                    format!(
+                        "{} ({}, untouched for {} days)",
+                        e.company,
+                        e.status.label(),
+                        e.idle_days
+                    )
+                })
+                .collect(),
+        ),
+    ];
+
+    let subject = format!("Your week in job applications, {}", data.now.date());
+    let mut text = format!("{subject}\n");
+    let mut html = format!("<h1>{}</h1>\n", escape_html(&subject));
+
+    if sections.iter().all(|(_, items)| items.is_empty()) {
+        text.push_str("\nNothing happened this week.\n");
+        html.push_str("<p>Nothing happened this week.</p>\n");
+    }
+
+    for (title, items) in sections.iter().filter(|(_, items)| !items.is_empty()) {
+        text.push_str(&format!("\n{title}\n"));
+        html.push_str(&format!("<h2>{title}</h2>\n<ul>\n"));
+        for item in items {
+            text.push_str(&format!("  - {item}\n"));
+            html.push_str(&format!("<li>{}</li>\n", escape_html(item)));
+        }
+        html.push_str("</ul>\n");
+    }
+
+    Digest {
+        subject,
+        text,
+        html,
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Waits until `now` is outside the quiet hours of the preferences. When
+/// they cannot be read the digest goes out anyway, as holding it back for
+/// good would be worse.
+async fn wait_for_quiet_hours(pool: &SqlitePool) {
+    let now = OffsetDateTime::now_utc();
+    let quiet_hours = match load_preferences(pool).await {
+        Ok(preferences) => preferences.quiet_hours(),
+        Err(e) => {
+            tracing::warn!("Failed to read quiet hours for the digest: {e}");
+            None
+        }
+    };
+    let Some(quiet_hours) = quiet_hours else {
+        return;
+    };
+
+    let wait = quiet_hours.next_allowed(now) - now;
+    if wait.is_positive() {
+        tracing::info!("holding the weekly digest back for {wait} of quiet hours");
+        tokio::time::sleep(wait.try_into().unwrap_or_default()).await;
+    }
+}
+
+/// When the digest last went out. The first start of the server counts as
+/// one, so the first digest follows a week later.
+async fn last_sent(pool: &SqlitePool, now: OffsetDateTime) -> Result<OffsetDateTime, sqlx::Error> {
+    sqlx::query("INSERT INTO digest_runs (id, sent_at) VALUES (1, ?) ON CONFLICT(id) DO NOTHING")
+        .bind(date_to_db(now))
+        .execute(pool)
+        .await?;
+    let sent_at: String = sqlx::query_scalar("SELECT sent_at FROM digest_runs WHERE id = 1")
+        .fetch_one(pool)
+        .await?;
+
+    date_from_db(&sent_at).map_err(|e| sqlx::Error::Decode(e.into()))
+}
+
+async fn record_sent(pool: &SqlitePool, sent_at: OffsetDateTime) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE digest_runs SET sent_at = ? WHERE id = 1")
+        .bind(date_to_db(sent_at))
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// When the next digest is due. One that fell due while the server was down
+/// is due right away, but the missed weeks are not caught up on one by one.
+async fn next_due(pool: &SqlitePool) -> OffsetDateTime {
+    let now = OffsetDateTime::now_utc();
+    match last_sent(pool, now).await {
+        Ok(last_sent) => last_sent + DIGEST_INTERVAL,
+        Err(e) => {
+            tracing::error!("Failed to read when the weekly digest last went out: {e}");
+            now + DIGEST_INTERVAL
+        }
+    }
+}
+
+/// Composes the digest once a week and logs it. There is no mail delivery
+/// yet; this is where it would go.
+pub fn spawn_weekly_digest(pool: SqlitePool) {
+    tokio::spawn(async move {
+        loop {
+            let wait = next_due(&pool).await - OffsetDateTime::now_utc();
+            if wait.is_positive() {
+                tokio::time::sleep(wait.try_into().unwrap_or_default()).await;
+            }
+            wait_for_quiet_hours(&pool).await;
+
+            let now = OffsetDateTime::now_utc();
+            match load_digest_data(&pool, now).await {
+                Ok(data) => {
+                    let digest = compose_digest(&data);
+                    tracing::info!(subject = %digest.subject, "weekly digest\n{}", digest.text);
+                    tracing::debug!(html = %digest.html, "weekly digest html");
+                }
+                Err(e) => tracing::error!("Failed to compose weekly digest: {e}"),
+            }
+            if let Err(e) = record_sent(&pool, now).await {
+                tracing::error!("Failed to record that the weekly digest went out: {e}");
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{date, datetime};
+
+    fn empty(now: OffsetDateTime) -> DigestData {
+        DigestData {
+            now,
+            new_applications: Vec::new(),
+            status_changes: Vec::new(),
+            upcoming_follow_ups: Vec::new(),
+            stale: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn a_quiet_week_says_so() {
+        let digest = compose_digest(&empty(datetime!(2026-02-16 09:00 UTC)));
+
+        assert_eq!(digest.subject, "Your week in job applications, 2026-02-16");
+        assert!(digest.text.contains("Nothing happened this week."));
+        assert!(!digest.html.contains("<ul>"));
+    }
+
+    #[test]
+    fn only_sections_with_entries_are_listed() {
+        let data = DigestData {
+            new_applications: vec![DigestEntry {
+                company: "Smith & <Sons>".into(),
+                status: Status::Solicitated,
+            }],
+            upcoming_follow_ups: vec![FollowUpEntry {
+                company: "Acme".into(),
+                date: date!(2026 - 02 - 18),
+            }],
+            ..empty(datetime!(2026-02-16 09:00 UTC))
+        };
+
+        let digest = compose_digest(&data);
+
+        assert!(digest
+            .text
+            .contains("New applications\n  - Smith & <Sons> (Applied)\n"));
+        assert!(digest
+            .text
+            .contains("Follow-ups this week\n  - Acme on 2026-02-18\n"));
+        assert!(!digest.text.contains("Status changes"));
+        assert!(!digest.text.contains("Nothing happened"));
+        assert!(digest
+            .html
+            .contains("<li>Smith &amp; &lt;Sons&gt; (Applied)</li>"));
+        assert!(!digest.html.contains("Waiting for a while"));
+    }
+
+    #[sqlx::test]
+    async fn the_last_send_survives_a_restart(pool: SqlitePool) {
+        let started = datetime!(2026-02-16 09:00 UTC);
+        assert_eq!(last_sent(&pool, started).await.unwrap(), started);
+
+        // A later start keeps the time of the first one
+        let restarted = started + Duration::days(3);
+        assert_eq!(last_sent(&pool, restarted).await.unwrap(), started);
+
+        let sent = started + DIGEST_INTERVAL;
+        record_sent(&pool, sent).await.unwrap();
+        assert_eq!(
+            last_sent(&pool, sent + Duration::days(1)).await.unwrap(),
+            sent
+        );
+    }
+}
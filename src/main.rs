@@ -1,11 +1,55 @@
+// The Leptos view types of the whole app nest deeper than the default allows
+#![recursion_limit = "256"]
+
+/// How SQLite trades durability for write speed, picked with the
+/// `DURABILITY` environment variable. Both presets use the write-ahead log,
+/// so readers never block the writer and a crash cannot corrupt the
+/// database; they differ in when SQLite waits for the disk.
+#[cfg(feature = "ssr")]
+#[derive(Clone, Copy, Debug)]
+enum Durability {
+    /// `synchronous=FULL`: every commit is flushed to disk before it
+    /// returns, so it survives a power cut. The default, for servers.
+    Safe,
+    /// `synchronous=NORMAL`: the disk is only flushed at checkpoints, which
+    /// makes bulk writes much faster. A power cut or OS crash may lose the
+    /// last commits, an app crash does not. Fine for a kiosk or a throwaway
+    /// instance.
+    Fast,
+}
+
+#[cfg(feature = "ssr")]
+impl Durability {
+    fn from_env() -> Self {
+        match std::env::var("DURABILITY").as_deref() {
+            Ok("fast") => Durability::Fast,
+            Ok("safe") | Err(_) => Durability::Safe,
+            Ok(other) => {
+                tracing::warn!("Unknown DURABILITY {other:?}, using \"safe\"");
+                Durability::Safe
+            }
+        }
+    }
+
+    fn synchronous(self) -> sqlx::sqlite::SqliteSynchronous {
+        match self {
+            Durability::Safe => sqlx::sqlite::SqliteSynchronous::Full,
+            Durability::Fast => sqlx::sqlite::SqliteSynchronous::Normal,
+        }
+    }
+}
+
 #[cfg(feature = "ssr")]
 #[tokio::main]
 async fn main() {
-    use axum::Router;
+    use axum::{middleware, Router};
     use leptos::prelude::*;
     use leptos_axum::{generate_route_list, LeptosRoutes};
     use soulcrush::app::*;
-    use sqlx::sqlite::SqlitePoolOptions;
+    use soulcrush::rate_limit::{self, RateLimiter};
+    use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
+    use std::net::SocketAddr;
+    use std::str::FromStr;
     use tower_http::trace::TraceLayer;
     use tracing::info;
     use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
@@ -20,12 +64,24 @@ async fn main() {
 
     dotenvy::dotenv().ok();
 
+    // An unreadable status badge is a bug in the stylesheet, so refuse to start
+    if let Err(e) = soulcrush::contrast::check_status_palette() {
+        panic!("{e}");
+    }
+
     // Database setup
     let database_url =
         std::env::var("DATABASE_URL").unwrap_or_else(|_| "sqlite:data.db?mode=rwc".into());
+    let durability = Durability::from_env();
+    info!("using {durability:?} durability");
+    // The pragmas are applied to every connection the pool opens
+    let options = SqliteConnectOptions::from_str(&database_url)
+        .expect("Invalid DATABASE_URL")
+        .journal_mode(SqliteJournalMode::Wal)
+        .synchronous(durability.synchronous());
     let pool = SqlitePoolOptions::new()
         .max_connections(5)
-        .connect(&database_url)
+        .connect_with(options)
         .await
         .expect("Failed to connect to database");
 
@@ -35,13 +91,20 @@ async fn main() {
         .await
         .expect("Failed to run migrations");
 
+    soulcrush::digest::spawn_weekly_digest(pool.clone());
+
     let conf = get_configuration(None).unwrap();
     let addr = conf.leptos_options.site_addr;
     let leptos_options = conf.leptos_options;
     // Generate the list of routes in your Leptos App
     let routes = generate_route_list(App);
+    // Only the public JSON endpoints and the share pages are rate limited;
+    // the app's own requests, like gallery previews, are not
+    let limiter = RateLimiter::from_env();
+    let api = soulcrush::api::router(pool.clone(), limiter.clone());
 
-    let app = Router::new()
+    // The API sets its own body limits, as uploads may be larger
+    let pages = Router::new()
         .leptos_routes_with_context(
             &leptos_options,
             routes,
@@ -57,13 +120,26 @@ async fn main() {
         )
         .fallback(leptos_axum::file_and_error_handler(shell))
         .with_state(leptos_options)
+        .layer(middleware::from_fn(soulcrush::api::limit_page_bodies));
+
+    let app = Router::new()
+        .merge(api)
+        .merge(pages)
+        .layer(middleware::from_fn_with_state(
+            limiter,
+            rate_limit::limit_share_pages,
+        ))
         .layer(TraceLayer::new_for_http());
 
     info!("listening on http://{}", &addr);
     let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
-    axum::serve(listener, app.into_make_service())
-        .await
-        .unwrap();
+    // The rate limiter tells clients apart by their address
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .unwrap();
 }
 
 #[cfg(not(feature = "ssr"))]
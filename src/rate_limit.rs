@@ -0,0 +1,261 @@
+//! Per-IP rate limiting for the routes meant for other clients than the app:
+//! the public JSON endpoints and the share pages, along with the server
+//! function the share pages load their data through. Everything else is
+//! not limited, since only the app itself calls it.
+//!
+//! Clients are told apart by the address they connect from. Behind a reverse
+//! proxy that is the proxy's, so every client would share one bucket; set
+//! `RATE_LIMIT_TRUST_FORWARDED_FOR` there to key by the address the proxy
+//! appends to `X-Forwarded-For` instead. Anywhere else that header is up to
+//! the client, so it is ignored by default.
+
+use crate::app::GetSharedApplication;
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{header, HeaderMap, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use leptos::server_fn::ServerFn;
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Requests a client may make in a burst, unless `RATE_LIMIT_BURST` says
+/// otherwise.
+const DEFAULT_BURST: u32 = 30;
+
+/// Requests a client may make per minute once its burst is used up, unless
+/// `RATE_LIMIT_PER_MINUTE` says otherwise.
+const DEFAULT_PER_MINUTE: u32 = 60;
+
+/// Past this many tracked clients, those whose bucket has refilled are
+/// forgotten, as they are indistinguishable from new ones.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// A token bucket per client IP, shared by every route it guards.
+#[derive(Clone)]
+pub struct RateLimiter(Arc<Limits>);
+
+struct Limits {
+    burst: f64,
+    per_second: f64,
+    /// Whether to key by the last `X-Forwarded-For` address rather than the
+    /// peer address.
+    trust_forwarded_for: bool,
+    buckets: Mutex<HashMap<IpAddr, Bucket>>,
+}
+
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(burst: u32, per_minute: u32) -> Self {
+        Self(Arc::new(Limits {
+            burst: f64::from(burst),
+            per_second: f64::from(per_minute) / 60.0,
+            trust_forwarded_for: false,
+            buckets: Mutex::new(HashMap::new()),
+        }))
+    }
+
+    /// Keys clients by the address a reverse proxy appended to
+    /// `X-Forwarded-For`, for servers only reachable through that proxy.
+    pub fn trusting_forwarded_for(self) -> Self {
+        let limits = Arc::into_inner(self.0).expect("limiter is not shared yet");
+        Self(Arc::new(Limits {
+            trust_forwarded_for: true,
+            ..limits
+        }))
+    }
+
+    /// Reads the limits from `RATE_LIMIT_BURST` and `RATE_LIMIT_PER_MINUTE`,
+    /// and whether to trust `X-Forwarded-For` from
+    /// `RATE_LIMIT_TRUST_FORWARDED_FOR`.
+    pub fn from_env() -> Self {
+        let limiter = Self::new(
+            env_limit("RATE_LIMIT_BURST", DEFAULT_BURST),
+            env_limit("RATE_LIMIT_PER_MINUTE", DEFAULT_PER_MINUTE),
+        );
+        match std::env::var("RATE_LIMIT_TRUST_FORWARDED_FOR").as_deref() {
+            Ok("1" | "true") => limiter.trusting_forwarded_for(),
+            _ => limiter,
+        }
+    }
+
+    /// The address to key the client of a request by.
+    fn client_ip(&self, peer: IpAddr, headers: &HeaderMap) -> IpAddr {
+        if !self.0.trust_forwarded_for {
+            return peer;
+        }
+        // The proxy appends the address it saw, so earlier entries are
+        // whatever the client sent
+        headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .next_back()
+            .and_then(|ip| ip.trim().parse().ok())
+            .unwrap_or(peer)
+    }
+
+    /// Takes a token from the bucket of `ip`, or says how long until the
+    /// next one is available.
+    fn check(&self, ip: IpAddr, now: Instant) -> Result<(), Duration> {
+        let limits = &self.0;
+        let mut buckets = limits.buckets.lock().unwrap_or_else(|e| e.into_inner());
+
+        if buckets.len() >= MAX_TRACKED_CLIENTS {
+            buckets.retain(|_, bucket| limits.refill(bucket, now) < limits.burst);
+        }
+
+        let bucket = buckets.entry(ip).or_insert(Bucket {
+            tokens: limits.burst,
+            refilled_at: now,
+        });
+        let tokens = limits.refill(bucket, now);
+        bucket.tokens = tokens;
+        bucket.refilled_at = now;
+
+        if tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - tokens) / limits.per_second))
+        }
+    }
+}
+
+impl Limits {
+    /// The tokens `bucket` holds at `now`, without updating it.
+    fn refill(&self, bucket: &Bucket, now: Instant) -> f64 {
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        (bucket.tokens + elapsed * self.per_second).min(self.burst)
+    }
+}
+
+fn env_limit(name: &str, default: u32) -> u32 {
+    match std::env::var(name) {
+        Err(_) => default,
+        Ok(value) => match value.parse() {
+            Ok(limit) if limit > 0 => limit,
+            _ => {
+                tracing::warn!("Invalid {name} {value:?}, using {default}");
+                default
+            }
+        },
+    }
+}
+
+/// Middleware answering 429 with `Retry-After` once the client's bucket is
+/// empty.
+pub async fn limit(
+    State(limiter): State<RateLimiter>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let ip = limiter.client_ip(addr.ip(), request.headers());
+    match limiter.check(ip, Instant::now()) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            tracing::debug!("rate limited {ip} on {}", request.uri().path());
+            // Round up, so a client waiting that long is sure to get through
+            let seconds = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, seconds.max(1).to_string())],
+                "Too many requests",
+            )
+                .into_response()
+        }
+    }
+}
+
+/// [`limit`] for the public `/share/` pages and the server function they
+/// load through only, so it can sit in front of the whole Leptos router.
+pub async fn limit_share_pages(
+    limiter: State<RateLimiter>,
+    addr: ConnectInfo<SocketAddr>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path = request.uri().path();
+    if path.starts_with("/share/") || path == GetSharedApplication::PATH {
+        limit(limiter, addr, request, next).await
+    } else {
+        next.run(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::Body,
+        extract::connect_info::MockConnectInfo,
+        http::{HeaderValue, Request},
+        middleware,
+        routing::{any, get},
+        Router,
+    };
+    use tower::ServiceExt;
+
+    fn app(limiter: RateLimiter) -> Router {
+        Router::new()
+            .route("/share/{token}", get(|| async { "shared" }))
+            .route(GetSharedApplication::PATH, any(|| async { "data" }))
+            .route("/", get(|| async { "home" }))
+            .layer(middleware::from_fn_with_state(limiter, limit_share_pages))
+            .layer(MockConnectInfo(SocketAddr::from(([192, 0, 2, 1], 4000))))
+    }
+
+    async fn status_of(app: &Router, uri: &str) -> StatusCode {
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+        app.clone().oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn requests_past_the_burst_get_429() {
+        let app = app(RateLimiter::new(3, 1));
+
+        for _ in 0..3 {
+            assert_eq!(status_of(&app, "/share/abc").await, StatusCode::OK);
+        }
+        assert_eq!(
+            status_of(&app, "/share/abc").await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        // The share page's data comes out of the same bucket
+        assert_eq!(
+            status_of(&app, GetSharedApplication::PATH).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        // The app's own pages are not limited
+        assert_eq!(status_of(&app, "/").await, StatusCode::OK);
+    }
+
+    #[test]
+    fn forwarded_for_is_only_used_when_trusted() {
+        let peer = IpAddr::from([10, 0, 0, 1]);
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            HeaderValue::from_static("198.51.100.7, 203.0.113.9"),
+        );
+
+        assert_eq!(RateLimiter::new(1, 1).client_ip(peer, &headers), peer);
+        assert_eq!(
+            RateLimiter::new(1, 1)
+                .trusting_forwarded_for()
+                .client_ip(peer, &headers),
+            IpAddr::from([203, 0, 113, 9])
+        );
+    }
+}